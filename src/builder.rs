@@ -0,0 +1,229 @@
+use crate::error::NucleotideError;
+use crate::sequence::PackedSequence;
+use crate::utils::{encode_internal, window_mask};
+use crate::MAX_BASES;
+
+/// Incrementally builds a [`PackedSequence`] from many arbitrary-length segments.
+///
+/// Segments do not need to be aligned to the 32 base block size, partially filled
+/// blocks are carried over and completed by subsequent segments.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{PackedSequence, SeqBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut builder = SeqBuilder::new();
+/// builder.append(b"ACGT")?.append(b"TTGCA")?;
+/// let seq = builder.build();
+///
+/// assert_eq!(seq, PackedSequence::new(b"ACGTTTGCA")?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SeqBuilder {
+    data: Vec<u64>,
+    length: usize,
+    /// Reusable buffer for encoding incoming segments
+    scratch: Vec<u64>,
+}
+
+impl SeqBuilder {
+    /// Creates a new empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of bases appended so far.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if no bases have been appended.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Appends a nucleotide segment to the end of the sequence.
+    ///
+    /// The segment is validated in full before being appended, so the builder is
+    /// left unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NucleotideError::InvalidBase` if the segment contains non-ACGT characters.
+    pub fn append(&mut self, seq: &[u8]) -> Result<&mut Self, NucleotideError> {
+        if seq.is_empty() {
+            return Ok(self);
+        }
+        self.scratch.clear();
+        encode_internal(seq, &mut self.scratch)?;
+
+        let mut remaining = seq.len();
+        for i in 0..self.scratch.len() {
+            let n_bases = remaining.min(32);
+            self.push_block(self.scratch[i], n_bases);
+            remaining -= n_bases;
+        }
+
+        Ok(self)
+    }
+
+    /// Appends the bases of a packed k-mer that extend past its overlap with the sequence.
+    ///
+    /// The first `overlap` bases of `kmer` are assumed to match the end of the
+    /// sequence and are skipped, so only the remaining `k - overlap` bases are
+    /// appended. This extends a contig by one base when `overlap` is `k - 1`.
+    /// The overlapping bases are not checked against the sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NucleotideError::InvalidLength` if `k` is greater than 32 or
+    /// `overlap` is not less than `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bitnuc::{as_2bit, PackedSequence, SeqBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut builder = SeqBuilder::new();
+    /// builder.append(b"ACGT")?;
+    /// builder.append_kmer_with_overlap(as_2bit(b"GTCC")?, 4, 2)?;
+    /// assert_eq!(builder.build(), PackedSequence::new(b"ACGTCC")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn append_kmer_with_overlap(
+        &mut self,
+        kmer: u64,
+        k: usize,
+        overlap: usize,
+    ) -> Result<(), NucleotideError> {
+        if k > MAX_BASES {
+            return Err(NucleotideError::InvalidLength(k));
+        }
+        if overlap >= k {
+            return Err(NucleotideError::InvalidLength(overlap));
+        }
+        let n_bases = k - overlap;
+        self.push_block((kmer >> (overlap * 2)) & window_mask(n_bases), n_bases);
+        Ok(())
+    }
+
+    /// Appends up to 32 packed bases, whose unused bits must be zeroed.
+    fn push_block(&mut self, block: u64, n_bases: usize) {
+        // Base offset of the next free position in the final block
+        let offset = self.length % 32;
+        if offset == 0 {
+            self.data.push(block);
+        } else {
+            // Fill the partial block and carry any overflow into a new one
+            if let Some(last) = self.data.last_mut() {
+                *last |= block << (offset * 2);
+            }
+            if offset + n_bases > MAX_BASES {
+                self.data.push(block >> (64 - offset * 2));
+            }
+        }
+        self.length += n_bases;
+    }
+
+    /// Consumes the builder and returns the accumulated sequence.
+    pub fn build(self) -> PackedSequence {
+        PackedSequence::from_raw_parts(self.data, self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_from_segments() {
+        let segments: [&[u8]; 3] = [
+            b"ACGTACGTAC",
+            b"TTGGCCAATTGGCCAATTGGCCAATTGGCCAAGG",
+            b"CATCATCATCATCATCATCATCATCA",
+        ];
+
+        let mut builder = SeqBuilder::new();
+        for segment in segments {
+            builder.append(segment).unwrap();
+        }
+        assert_eq!(builder.len(), 70);
+
+        let expected = PackedSequence::new(&segments.concat()).unwrap();
+        assert_eq!(builder.build(), expected);
+    }
+
+    #[test]
+    fn test_build_block_aligned_segments() {
+        let segment = [b'G'; 32];
+        let mut builder = SeqBuilder::new();
+        builder.append(&segment).unwrap().append(&segment).unwrap();
+
+        let expected = PackedSequence::new(&[b'G'; 64]).unwrap();
+        assert_eq!(builder.build(), expected);
+    }
+
+    #[test]
+    fn test_build_empty() {
+        let mut builder = SeqBuilder::new();
+        builder.append(b"").unwrap();
+        assert!(builder.is_empty());
+        assert_eq!(builder.build(), PackedSequence::new(b"").unwrap());
+    }
+
+    #[test]
+    fn test_invalid_segment_leaves_builder_unchanged() {
+        let mut builder = SeqBuilder::new();
+        builder.append(b"ACG").unwrap();
+        assert!(builder.append(b"TTNA").is_err());
+        builder.append(b"T").unwrap();
+        assert_eq!(builder.build(), PackedSequence::new(b"ACGT").unwrap());
+    }
+
+    #[test]
+    fn test_append_kmer_with_overlap() {
+        let mut builder = SeqBuilder::new();
+        builder.append(b"ACGT").unwrap();
+        builder
+            .append_kmer_with_overlap(crate::as_2bit(b"CGTA").unwrap(), 4, 3)
+            .unwrap();
+        assert_eq!(builder.build(), PackedSequence::new(b"ACGTA").unwrap());
+    }
+
+    #[test]
+    fn test_append_kmer_across_blocks() {
+        // Extend a contig one base at a time across a block boundary
+        let contig = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCTAGG";
+        let k = 11;
+        let mut builder = SeqBuilder::new();
+        builder
+            .append_kmer_with_overlap(crate::as_2bit(&contig[..k]).unwrap(), k, 0)
+            .unwrap();
+        for start in 1..=contig.len() - k {
+            let kmer = crate::as_2bit(&contig[start..start + k]).unwrap();
+            builder.append_kmer_with_overlap(kmer, k, k - 1).unwrap();
+        }
+        assert_eq!(builder.len(), contig.len());
+        assert_eq!(builder.build(), PackedSequence::new(contig).unwrap());
+    }
+
+    #[test]
+    fn test_append_kmer_with_overlap_errors() {
+        let mut builder = SeqBuilder::new();
+        assert_eq!(
+            builder.append_kmer_with_overlap(0, 4, 4),
+            Err(NucleotideError::InvalidLength(4))
+        );
+        assert_eq!(
+            builder.append_kmer_with_overlap(0, 33, 1),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert!(builder.is_empty());
+    }
+}
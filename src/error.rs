@@ -1,8 +1,10 @@
-use std::fmt;
+use std::{fmt, io};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum NucleotideError {
     InvalidBase(u8),
+    NonAscii(u8),
     SequenceTooLong(usize),
     InvalidLength(usize),
     IndexOutOfBounds {
@@ -15,12 +17,32 @@ pub enum NucleotideError {
         length: usize,
     },
     Unsupported,
+    EmptySequence,
+    InvalidAlphabet([u8; 4]),
+    MixedAlphabet,
+    MalformedRecord,
+    ExpansionTooLarge(usize),
+    TooManyAmbiguities,
+}
+
+impl NucleotideError {
+    /// Classifies a byte that is not a valid nucleotide.
+    ///
+    /// Bytes with the high bit set are reported as `NonAscii`, all others as `InvalidBase`.
+    pub(crate) fn invalid_byte(byte: u8) -> Self {
+        if byte.is_ascii() {
+            NucleotideError::InvalidBase(byte)
+        } else {
+            NucleotideError::NonAscii(byte)
+        }
+    }
 }
 
 impl fmt::Display for NucleotideError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             NucleotideError::InvalidBase(b) => write!(f, "Invalid nucleotide base: {}", b),
+            NucleotideError::NonAscii(b) => write!(f, "Non-ASCII byte: {:#04x}", b),
             NucleotideError::SequenceTooLong(len) => {
                 write!(f, "Sequence length {} exceeds maximum", len)
             }
@@ -40,8 +62,58 @@ impl fmt::Display for NucleotideError {
                 )
             }
             NucleotideError::Unsupported => write!(f, "Unsupported architecture"),
+            NucleotideError::EmptySequence => write!(f, "Sequence is empty"),
+            NucleotideError::InvalidAlphabet(alphabet) => {
+                write!(f, "Invalid alphabet: {:?}", alphabet)
+            }
+            NucleotideError::MixedAlphabet => {
+                write!(f, "Sequence mixes DNA (T) and RNA (U) bases")
+            }
+            NucleotideError::MalformedRecord => write!(f, "Malformed sequence record"),
+            NucleotideError::ExpansionTooLarge(n) => {
+                write!(f, "Ambiguity expansion of {} sequences exceeds maximum", n)
+            }
+            NucleotideError::TooManyAmbiguities => {
+                write!(f, "Sequence contains more than one ambiguous base")
+            }
         }
     }
 }
 
 impl std::error::Error for NucleotideError {}
+
+#[derive(Debug)]
+pub enum ValidationError {
+    InvalidBase { offset: usize, base: u8 },
+    Io(io::Error),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::InvalidBase { offset, base } => {
+                write!(
+                    f,
+                    "Invalid nucleotide base: {} at byte offset {}",
+                    base, offset
+                )
+            }
+            ValidationError::Io(err) => write!(f, "I/O error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidationError::Io(err) => Some(err),
+            ValidationError::InvalidBase { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ValidationError {
+    fn from(err: io::Error) -> Self {
+        ValidationError::Io(err)
+    }
+}
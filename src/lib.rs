@@ -207,18 +207,56 @@
 //! See the documentation for [`as_2bit`] and [`from_2bit`] for more details on
 //! working with packed sequences directly.
 
+mod builder;
 mod error;
+mod macros;
+mod packed;
 mod sequence;
 mod utils;
 
-pub use error::NucleotideError;
+pub use builder::SeqBuilder;
+pub use error::{NucleotideError, ValidationError};
+#[doc(hidden)]
+pub use macros::as_2bit_const;
+pub use packed::{MaskedPacked, Packed64};
 pub use sequence::PackedSequence;
 pub use utils::{
+    active_backend, all_invalid_positions,
     analysis::{BaseCount, GCContent},
-    as_2bit, decode, encode, encode_alloc, from_2bit, from_2bit_alloc, hdist, hdist_scalar,
-    split_packed,
+    as_2bit, as_2bit_batch_uniform, as_2bit_bitsliced, as_2bit_both_strands, as_2bit_bytes,
+    as_2bit_fixed, as_2bit_ignore_whitespace, as_2bit_iupac_lossy, as_2bit_lossy_with,
+    as_2bit_nucleic, as_2bit_one_ambiguous, as_2bit_reversed, as_2bit_split, as_2bit_u128,
+    as_2bit_with_alphabet, as_2bit_with_backend, as_2bit_with_coords, as_2bit_with_sentinel,
+    base_at_2bit, base_at_with_sentinel, blocks_eq, blocks_to_fasta_string, build_lookup,
+    canonical_bases, canonical_fingerprint_blocks, canonical_key_2bit, canonical_kmers, cmp_2bit,
+    complement_base, compression_ratio, concat_2bit, consensus_base, contains_kmer_blocks,
+    count_base_blocks, decode, decode_records, deinterleave_2bit, diff_bases_2bit, encode,
+    encode_alloc, eq_masked_2bit, expand_iupac, find_palindromes, fingerprint, from_2bit,
+    from_2bit_alloc, from_2bit_bitsliced, from_2bit_bytes, from_2bit_padded, from_2bit_u128,
+    from_2bit_with_alphabet, from_bits_be, from_rle_2bit, fuse_kmers, gc_count_2bit,
+    gc_count_blocks, gc_fraction_2bit, gc_fraction_2bit_strict, hamming1_neighbors,
+    hamming_against_many, hamming_blocks, hash_canonical, hash_kmer, hdist, hdist_scalar,
+    interleave_2bit, is_homopolymer_2bit, is_palindrome_2bit, is_reverse_complement_2bit,
+    is_well_formed_2bit, jaccard_blocks, kmer_at_blocks, kmer_complexity, kmer_cosine,
+    levenshtein_banded_2bit, longest_common_substring_2bit, lsh_bucket, merge_lossy_2bit,
+    min_rotation_2bit, minhash_similarity, minhash_sketch, nearest_2bit, normalize_nucleotides,
+    one_hot_2bit, pack_column, pack_fasta_sequence, pack_fastq_record, packed_size_bytes,
+    poly_tail_len_2bit, position_frequency_matrix, positional_entropy, predecessors_2bit,
+    prefix_2bit, present_bases_2bit, rank_base_2bit, repeat_period_2bit, revcomp_bases,
+    reverse_complement_2bit, reverse_complement_2bit_alphabet, reverse_complement_blocks,
+    rolling_gc, rotate_left_2bit, rotate_right_2bit, run_length_histogram_2bit, sliding_gc,
+    split_fused, split_packed, successors_2bit, suffix_2bit, tiles_2bit, to_bits_be, to_rle_2bit,
+    trim_by_mask_2bit, ts_tv_blocks, ungapped_score_2bit, validate_batch, validate_reader, Backend,
+    BarcodeIndex, BatchReport, KmerHash, KmerHasher, PackExt, PackedMap, PackedReader,
+    ASCII_TO_CODE, COMPLEMENT_CODE, INVALID_CODE, SENTINEL,
 };
 
+/// The number of bases a single 2-bit packed u64 can hold
+pub const MAX_BASES: usize = 32;
+
+/// The number of bases a single 2-bit packed u128 can hold
+pub const MAX_BASES_U128: usize = 64;
+
 #[cfg(test)]
 mod testing {
     use crate::{BaseCount, GCContent, PackedSequence};
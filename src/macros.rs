@@ -0,0 +1,78 @@
+use crate::utils::packing::{ASCII_TO_CODE, INVALID_CODE};
+use crate::MAX_BASES;
+
+/// Packs a nucleotide sequence into a u64 in a const context.
+///
+/// This backs the [`two_bit!`](crate::two_bit) macro and panics (which is a
+/// compile error when evaluated in a const) instead of returning an error.
+#[doc(hidden)]
+pub const fn as_2bit_const(seq: &[u8]) -> u64 {
+    if seq.len() > MAX_BASES {
+        panic!("two_bit! literal exceeds 32 bases");
+    }
+
+    let mut packed = 0u64;
+    let mut i = 0;
+    while i < seq.len() {
+        let bits = ASCII_TO_CODE[seq[i] as usize];
+        if bits == INVALID_CODE {
+            panic!("two_bit! literal contains an invalid nucleotide");
+        }
+        packed |= (bits as u64) << (i * 2);
+        i += 1;
+    }
+    packed
+}
+
+/// Packs a nucleotide string literal into a 2-bit encoded u64 at compile time.
+///
+/// The literal is validated during compilation, so typos in embedded primers or
+/// adapters are caught at build time rather than at runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, two_bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// const ADAPTER: u64 = two_bit!("AGATCGGAAGAGC");
+/// assert_eq!(ADAPTER, as_2bit(b"AGATCGGAAGAGC")?);
+/// assert_eq!(two_bit!("ACGT"), 0b11100100);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Invalid bases fail to compile:
+///
+/// ```compile_fail
+/// let packed = bitnuc::two_bit!("ACGN");
+/// ```
+///
+/// As do literals longer than 32 bases:
+///
+/// ```compile_fail
+/// let packed = bitnuc::two_bit!("ACGTACGTACGTACGTACGTACGTACGTACGTA");
+/// ```
+#[macro_export]
+macro_rules! two_bit {
+    ($seq:literal) => {{
+        const PACKED: u64 = $crate::as_2bit_const($seq.as_bytes());
+        PACKED
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::as_2bit;
+
+    #[test]
+    fn test_two_bit_macro() {
+        assert_eq!(two_bit!("ACGT"), as_2bit(b"ACGT").unwrap());
+        assert_eq!(two_bit!("acgt"), as_2bit(b"ACGT").unwrap());
+        assert_eq!(two_bit!(""), 0);
+        assert_eq!(
+            two_bit!("ACTGGAAAATTTTAAGGACTGGAAAATTTTAA"),
+            as_2bit(b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAA").unwrap()
+        );
+    }
+}
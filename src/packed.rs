@@ -0,0 +1,193 @@
+use crate::utils::functions::BASES;
+use crate::utils::packing::base_to_2bit;
+use crate::{NucleotideError, MAX_BASES};
+
+/// A single block of up to 32 2-bit packed bases.
+///
+/// `Packed64` is a transparent wrapper around the `u64` produced by
+/// [`as_2bit`](crate::as_2bit) and stored in block buffers by
+/// [`encode`](crate::encode). Its layout is identical to `u64`, so slices of
+/// blocks can be reinterpreted without copying.
+///
+/// With the `bytemuck` feature enabled, `Packed64` implements `Pod` and
+/// `Zeroable` so that `&[Packed64]` can be cast to and from `&[u8]`, e.g. to
+/// read blocks directly out of a memory-mapped file.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, Packed64};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let block = Packed64::from(as_2bit(b"ACGT")?);
+/// assert_eq!(block.0, 0b11100100);
+/// assert_eq!(u64::from(block), 0b11100100);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Packed64(pub u64);
+
+impl From<u64> for Packed64 {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Packed64> for u64 {
+    fn from(value: Packed64) -> Self {
+        value.0
+    }
+}
+
+// SAFETY: `Packed64` is `repr(transparent)` over `u64`, which is `Pod`
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Packed64 {}
+
+// SAFETY: `Packed64` is `repr(transparent)` over `u64`, which is `Pod`
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Packed64 {}
+
+/// A 2-bit packed sequence that remembers where it held `N` bases.
+///
+/// 2-bit encoding cannot represent `N`, so [`MaskedPacked::encode`] packs each
+/// `N` as `A` and sets its position in `n_positions`. [`MaskedPacked::decode`]
+/// then restores the `N`s, so sequences of up to 32 bases with any number of
+/// `N`s round-trip losslessly (with bases uppercased).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, MaskedPacked};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let masked = MaskedPacked::encode(b"ANNT")?;
+/// assert_eq!(masked.packed, as_2bit(b"AAAT")?);
+/// assert_eq!(masked.n_positions, 0b0110);
+/// assert_eq!(masked.decode()?, b"ANNT");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaskedPacked {
+    /// The 2-bit packed sequence, with `N` positions packed as `A`
+    pub packed: u64,
+    /// The number of bases in the sequence
+    pub len: usize,
+    /// Bit `i` is set if base `i` was originally `N`
+    pub n_positions: u32,
+}
+
+impl MaskedPacked {
+    /// Packs a nucleotide sequence, recording the positions of its `N` bases.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer than 32 bases.
+    ///
+    /// Returns `NucleotideError::InvalidBase` if the sequence contains characters
+    /// other than A,C,G,T,N (case insensitive).
+    pub fn encode(seq: &[u8]) -> Result<Self, NucleotideError> {
+        if seq.len() > MAX_BASES {
+            return Err(NucleotideError::SequenceTooLong(seq.len()));
+        }
+
+        let mut masked = Self {
+            len: seq.len(),
+            ..Self::default()
+        };
+        for (i, &base) in seq.iter().enumerate() {
+            match base {
+                b'N' | b'n' => masked.n_positions |= 1 << i,
+                base => masked.packed |= base_to_2bit(base)? << (i * 2),
+            }
+        }
+        Ok(masked)
+    }
+
+    /// Unpacks the sequence, emitting `N` at every recorded position.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+    pub fn decode(&self) -> Result<Vec<u8>, NucleotideError> {
+        if self.len > MAX_BASES {
+            return Err(NucleotideError::InvalidLength(self.len));
+        }
+
+        Ok((0..self.len)
+            .map(|i| {
+                if (self.n_positions >> i) & 1 == 1 {
+                    b'N'
+                } else {
+                    BASES[((self.packed >> (i * 2)) & 0b11) as usize]
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packed64_conversions() {
+        let block = Packed64::from(0b11100100);
+        assert_eq!(block, Packed64(0b11100100));
+        assert_eq!(u64::from(block), 0b11100100);
+        assert_eq!(Packed64::default(), Packed64(0));
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_packed64_cast_slice() {
+        let blocks = [
+            Packed64(crate::as_2bit(b"ACGT").unwrap()),
+            Packed64(u64::MAX),
+            Packed64(0),
+        ];
+
+        let bytes: &[u8] = bytemuck::cast_slice(&blocks);
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[..8], &blocks[0].0.to_ne_bytes());
+
+        let restored: &[Packed64] = bytemuck::cast_slice(bytes);
+        assert_eq!(restored, &blocks);
+    }
+
+    #[test]
+    fn test_masked_packed_round_trip() {
+        let masked = MaskedPacked::encode(b"ACNGT").unwrap();
+        assert_eq!(masked.packed, crate::as_2bit(b"ACAGT").unwrap());
+        assert_eq!(masked.len, 5);
+        assert_eq!(masked.n_positions, 0b00100);
+        assert_eq!(masked.decode().unwrap(), b"ACNGT");
+    }
+
+    #[test]
+    fn test_masked_packed_edge_cases() {
+        let all_n = MaskedPacked::encode(&[b'n'; 32]).unwrap();
+        assert_eq!((all_n.packed, all_n.n_positions), (0, u32::MAX));
+        assert_eq!(all_n.decode().unwrap(), vec![b'N'; 32]);
+
+        let empty = MaskedPacked::encode(b"").unwrap();
+        assert_eq!(empty, MaskedPacked::default());
+        assert_eq!(empty.decode().unwrap(), b"");
+
+        assert_eq!(
+            MaskedPacked::encode(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+        assert_eq!(
+            MaskedPacked::encode(b"ACRT"),
+            Err(NucleotideError::InvalidBase(b'R'))
+        );
+        let invalid = MaskedPacked {
+            len: 33,
+            ..MaskedPacked::default()
+        };
+        assert_eq!(invalid.decode(), Err(NucleotideError::InvalidLength(33)));
+    }
+}
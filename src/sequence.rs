@@ -1,7 +1,14 @@
 use crate::encode;
 use crate::error::NucleotideError;
+use std::io::{self, Read, Write};
 use std::ops::Range;
 
+/// Magic bytes identifying the binary format written by [`PackedSequence::write_bytes`]
+const MAGIC: &[u8; 4] = b"BNUC";
+
+/// Version of the binary format written by [`PackedSequence::write_bytes`]
+const FORMAT_VERSION: u8 = 1;
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct PackedSequence {
     data: Vec<u64>,
@@ -51,6 +58,14 @@ impl PackedSequence {
         })
     }
 
+    /// Creates a `PackedSequence` from already encoded blocks.
+    ///
+    /// The caller is responsible for ensuring that `data` holds exactly
+    /// `length.div_ceil(32)` blocks with zeroed unused bits.
+    pub(crate) fn from_raw_parts(data: Vec<u64>, length: usize) -> Self {
+        Self { data, length }
+    }
+
     /// Returns the number of bases in the sequence.
     ///
     /// # Examples
@@ -260,12 +275,150 @@ impl PackedSequence {
     pub fn to_vec(&self) -> Result<Vec<u8>, NucleotideError> {
         self.slice(0..self.length)
     }
+
+    /// Writes the sequence to a writer in a compact self-describing binary format.
+    ///
+    /// The layout is:
+    ///
+    /// ```text
+    /// magic   : 4 bytes  b"BNUC"
+    /// version : 1 byte   (currently 1)
+    /// length  : LEB128 varint number of bases
+    /// blocks  : ceil(length / 32) u64 values in little-endian
+    /// ```
+    ///
+    /// The layout is independent of the in-memory representation and of the
+    /// endianness of the host.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bitnuc::PackedSequence;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let seq = PackedSequence::new(b"ACGT")?;
+    /// let mut buffer = Vec::new();
+    /// seq.write_bytes(&mut buffer)?;
+    /// assert_eq!(&buffer[..4], b"BNUC");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error raised by the writer.
+    pub fn write_bytes<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(MAGIC)?;
+        w.write_all(&[FORMAT_VERSION])?;
+
+        // Write the length as an unsigned LEB128 varint
+        let mut length = self.length as u64;
+        loop {
+            let byte = (length & 0x7F) as u8;
+            length >>= 7;
+            if length == 0 {
+                w.write_all(&[byte])?;
+                break;
+            }
+            w.write_all(&[byte | 0x80])?;
+        }
+
+        for block in &self.data {
+            w.write_all(&block.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Reads a sequence from a reader in the format written by [`PackedSequence::write_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bitnuc::PackedSequence;
+    /// use std::io::Cursor;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let seq = PackedSequence::new(b"ACGT")?;
+    /// let mut buffer = Vec::new();
+    /// seq.write_bytes(&mut buffer)?;
+    ///
+    /// let decoded = PackedSequence::read_bytes(&mut Cursor::new(buffer))?;
+    /// assert_eq!(decoded, seq);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind `io::ErrorKind::InvalidData` if the magic bytes or
+    /// version do not match, if the length is malformed, or if the final block
+    /// has bits set beyond the sequence length.
+    ///
+    /// Returns any I/O error raised by the reader (including `UnexpectedEof` on truncated input).
+    pub fn read_bytes<R: Read>(r: &mut R) -> io::Result<Self> {
+        let mut magic = [0u8; 4];
+        r.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("invalid magic bytes"));
+        }
+
+        let mut version = [0u8; 1];
+        r.read_exact(&mut version)?;
+        if version[0] != FORMAT_VERSION {
+            return Err(invalid_data("unsupported format version"));
+        }
+
+        // Read the length as an unsigned LEB128 varint
+        let mut length = 0u64;
+        let mut shift = 0;
+        loop {
+            let mut byte = [0u8; 1];
+            r.read_exact(&mut byte)?;
+            // The tenth byte only has room for the top bit of a u64
+            if shift >= 64 || (shift == 63 && byte[0] & 0x7E != 0) {
+                return Err(invalid_data("sequence length overflows u64"));
+            }
+            length |= u64::from(byte[0] & 0x7F) << shift;
+            if byte[0] & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        let length =
+            usize::try_from(length).map_err(|_| invalid_data("sequence length overflows usize"))?;
+
+        let n_chunks = length.div_ceil(32);
+        // The length is untrusted, so only reserve a bounded amount up front and
+        // let truncated input fail on the reads below
+        let mut data = Vec::with_capacity(n_chunks.min(1 << 16));
+        let mut block = [0u8; 8];
+        for _ in 0..n_chunks {
+            r.read_exact(&mut block)?;
+            data.push(u64::from_le_bytes(block));
+        }
+
+        // Reject stray bits beyond the end of the sequence
+        let rem = length % 32;
+        if let (Some(last), true) = (data.last(), rem > 0) {
+            if last >> (rem * 2) != 0 {
+                return Err(invalid_data("unused bits set in final block"));
+            }
+        }
+
+        Ok(Self { data, length })
+    }
 }
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::error::NucleotideError;
     use crate::sequence::PackedSequence;
     use std::collections::HashSet;
+    use std::io::{Cursor, ErrorKind};
 
     #[test]
     fn test_new_sequence() {
@@ -336,4 +489,84 @@ mod tests {
         assert!(set.contains(&seq2));
         assert!(!set.contains(&seq3));
     }
+
+    #[test]
+    fn test_binary_round_trip() {
+        for len in [0, 1, 31, 32, 33, 200] {
+            let bases: Vec<u8> = (0..len).map(|i| b"ACGT"[(i * 7) % 4]).collect();
+            let seq = PackedSequence::new(&bases).unwrap();
+
+            let mut cursor = Cursor::new(Vec::new());
+            seq.write_bytes(&mut cursor).unwrap();
+            cursor.set_position(0);
+
+            let decoded = PackedSequence::read_bytes(&mut cursor).unwrap();
+            assert_eq!(decoded, seq);
+            assert_eq!(decoded.to_vec().unwrap(), bases);
+        }
+    }
+
+    #[test]
+    fn test_binary_layout() {
+        let seq = PackedSequence::new(&[b'T'; 200]).unwrap();
+        let mut buffer = Vec::new();
+        seq.write_bytes(&mut buffer).unwrap();
+
+        // magic + version + 2 byte varint + 7 blocks
+        assert_eq!(&buffer[..5], b"BNUC\x01");
+        assert_eq!(&buffer[5..7], &[0xC8, 0x01]);
+        assert_eq!(buffer.len(), 7 + 7 * 8);
+    }
+
+    #[test]
+    fn test_binary_invalid_header() {
+        let err = PackedSequence::read_bytes(&mut Cursor::new(b"XNUC\x01\x00".to_vec()));
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::InvalidData);
+
+        let err = PackedSequence::read_bytes(&mut Cursor::new(b"BNUC\x02\x00".to_vec()));
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_binary_truncated() {
+        let seq = PackedSequence::new(b"ACGTACGT").unwrap();
+        let mut buffer = Vec::new();
+        seq.write_bytes(&mut buffer).unwrap();
+        buffer.pop();
+
+        let err = PackedSequence::read_bytes(&mut Cursor::new(buffer));
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_binary_stray_bits() {
+        let mut buffer = b"BNUC\x01\x02".to_vec();
+        buffer.extend_from_slice(&u64::MAX.to_le_bytes());
+
+        let err = PackedSequence::read_bytes(&mut Cursor::new(buffer));
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_binary_huge_length() {
+        // A length of u64::MAX with no blocks behind it
+        let mut buffer = b"BNUC\x01".to_vec();
+        buffer.extend_from_slice(&[0xFF; 9]);
+        buffer.push(0x01);
+        assert_eq!(buffer.len(), 15);
+
+        let err = PackedSequence::read_bytes(&mut Cursor::new(buffer));
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_binary_length_overflow() {
+        // The tenth varint byte carries more than the top bit of a u64
+        let mut buffer = b"BNUC\x01".to_vec();
+        buffer.extend_from_slice(&[0x80; 9]);
+        buffer.push(0x02);
+
+        let err = PackedSequence::read_bytes(&mut Cursor::new(buffer));
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
 }
@@ -0,0 +1,69 @@
+use crate::{NucleotideError, MAX_BASES};
+
+/// ASCII nucleotide for each 2-bit code
+pub(crate) const BASES: [u8; 4] = *b"ACGT";
+
+/// Returns the ASCII nucleotide at a position of a 2-bit packed sequence.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+/// * `index` - The position of the base to extract
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+/// Returns `NucleotideError::IndexOutOfBounds` if `index` is not less than `len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, base_at_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACGT")?;
+/// assert_eq!(base_at_2bit(packed, 4, 2)?, b'G');
+/// assert!(base_at_2bit(packed, 4, 4).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn base_at_2bit(packed: u64, len: usize, index: usize) -> Result<u8, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if index >= len {
+        return Err(NucleotideError::IndexOutOfBounds { index, length: len });
+    }
+    Ok(BASES[((packed >> (index * 2)) & 0b11) as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_base_at_2bit() {
+        let seq = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA";
+        let packed = as_2bit(seq).unwrap();
+        for (i, &base) in seq.iter().enumerate() {
+            assert_eq!(base_at_2bit(packed, seq.len(), i), Ok(base));
+        }
+    }
+
+    #[test]
+    fn test_base_at_2bit_errors() {
+        assert_eq!(
+            base_at_2bit(0, 4, 4),
+            Err(NucleotideError::IndexOutOfBounds {
+                index: 4,
+                length: 4
+            })
+        );
+        assert_eq!(
+            base_at_2bit(0, 33, 0),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
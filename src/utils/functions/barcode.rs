@@ -0,0 +1,189 @@
+use super::hamming::masked_distance;
+use crate::utils::window_mask;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Maximum number of leading bases used to group barcodes
+const MAX_PREFIX_LEN: usize = 6;
+
+/// An index over equal-length 2-bit packed barcodes for nearest-neighbor lookup.
+///
+/// Barcodes are grouped by their leading bases. At query time the distance
+/// between the query prefix and each group prefix is a lower bound on the
+/// distance to every barcode in the group, so whole groups are skipped once they
+/// cannot beat the best match found so far or exceed the distance limit.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, BarcodeIndex};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let barcodes = [as_2bit(b"ACGTACGT")?, as_2bit(b"TTTTGGGG")?, as_2bit(b"ACGTTTTT")?];
+/// let index = BarcodeIndex::new(&barcodes, 8)?;
+///
+/// let query = as_2bit(b"ACGTTTTA")?;
+/// assert_eq!(index.nearest(query, 1), Some((2, 1)));
+/// assert_eq!(index.nearest(as_2bit(b"CCCCCCCC")?, 2), None);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BarcodeIndex {
+    barcodes: Vec<u64>,
+    len: usize,
+    prefix_len: usize,
+    /// Barcode indices grouped by prefix, sorted by prefix
+    groups: Vec<(u64, Vec<usize>)>,
+}
+
+impl BarcodeIndex {
+    /// Builds an index over barcodes of `len` bases each.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+    pub fn new(barcodes: &[u64], len: usize) -> Result<Self, NucleotideError> {
+        if len > MAX_BASES {
+            return Err(NucleotideError::InvalidLength(len));
+        }
+        let prefix_len = len.min(MAX_PREFIX_LEN);
+        let prefix_mask = window_mask(prefix_len);
+
+        let mut keyed: Vec<(u64, usize)> = barcodes
+            .iter()
+            .enumerate()
+            .map(|(i, &barcode)| (barcode & prefix_mask, i))
+            .collect();
+        keyed.sort_unstable();
+
+        let mut groups: Vec<(u64, Vec<usize>)> = Vec::new();
+        for (prefix, i) in keyed {
+            match groups.last_mut() {
+                Some((last, members)) if *last == prefix => members.push(i),
+                _ => groups.push((prefix, vec![i])),
+            }
+        }
+
+        Ok(Self {
+            barcodes: barcodes.iter().map(|&b| b & window_mask(len)).collect(),
+            len,
+            prefix_len,
+            groups,
+        })
+    }
+
+    /// The number of barcodes in the index
+    pub fn len(&self) -> usize {
+        self.barcodes.len()
+    }
+
+    /// Whether the index holds no barcodes
+    pub fn is_empty(&self) -> bool {
+        self.barcodes.is_empty()
+    }
+
+    /// Finds the barcode closest to `query` by hamming distance.
+    ///
+    /// Ties are resolved in favor of the earliest barcode.
+    ///
+    /// # Returns
+    ///
+    /// Returns the index and distance of the closest barcode, or `None` if no
+    /// barcode is within `max_dist`.
+    pub fn nearest(&self, query: u64, max_dist: u32) -> Option<(usize, u32)> {
+        let mask = window_mask(self.len);
+        let prefix_mask = window_mask(self.prefix_len);
+
+        let mut best: Option<(usize, u32)> = None;
+        for (prefix, members) in &self.groups {
+            // The prefix distance bounds the distance to every member
+            let bound = best.map_or(max_dist, |(_, dist)| dist.min(max_dist));
+            if masked_distance(query, *prefix, prefix_mask) > bound {
+                continue;
+            }
+
+            for &i in members {
+                let dist = masked_distance(query, self.barcodes[i], mask);
+                if dist > max_dist {
+                    continue;
+                }
+                let better = best.is_none_or(|(best_i, best_dist)| (dist, i) < (best_dist, best_i));
+                if better {
+                    best = Some((i, dist));
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{as_2bit, hdist_scalar};
+
+    fn brute_force(
+        barcodes: &[u64],
+        query: u64,
+        len: usize,
+        max_dist: u32,
+    ) -> Option<(usize, u32)> {
+        barcodes
+            .iter()
+            .enumerate()
+            .map(|(i, &b)| (i, hdist_scalar(query, b, len).unwrap()))
+            .filter(|&(_, dist)| dist <= max_dist)
+            .min_by_key(|&(i, dist)| (dist, i))
+    }
+
+    #[test]
+    fn test_barcode_index_matches_brute_force() {
+        let seqs: [&[u8]; 8] = [
+            b"ACGTACGTAC",
+            b"ACGTACGTTT",
+            b"TTGCAACGTA",
+            b"GGGGCCCCAA",
+            b"ACGTTCGTAC",
+            b"CATGCATGCA",
+            b"TTGCAACGTT",
+            b"ACGTACGTAC",
+        ];
+        let barcodes: Vec<u64> = seqs.iter().map(|s| as_2bit(s).unwrap()).collect();
+        let index = BarcodeIndex::new(&barcodes, 10).unwrap();
+        assert_eq!(index.len(), 8);
+
+        let queries: [&[u8]; 5] = [
+            b"ACGTACGTAC",
+            b"TTGCAACGTC",
+            b"GGGGCCCCTT",
+            b"CATGCATGGG",
+            b"AAAAAAAAAA",
+        ];
+        for query in queries {
+            let query = as_2bit(query).unwrap();
+            for max_dist in 0..=10 {
+                assert_eq!(
+                    index.nearest(query, max_dist),
+                    brute_force(&barcodes, query, 10, max_dist)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_barcode_index_edge_cases() {
+        let index = BarcodeIndex::new(&[], 8).unwrap();
+        assert!(index.is_empty());
+        assert_eq!(index.nearest(0, 8), None);
+
+        assert!(matches!(
+            BarcodeIndex::new(&[0], 33),
+            Err(NucleotideError::InvalidLength(33))
+        ));
+
+        // Short barcodes use the whole sequence as their prefix
+        let barcodes = [as_2bit(b"AC").unwrap(), as_2bit(b"GT").unwrap()];
+        let index = BarcodeIndex::new(&barcodes, 2).unwrap();
+        assert_eq!(index.nearest(as_2bit(b"GA").unwrap(), 1), Some((1, 1)));
+    }
+}
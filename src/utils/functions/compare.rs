@@ -0,0 +1,305 @@
+use std::cmp::Ordering;
+
+use crate::utils::{expand_base_mask, window_mask, LOWER_BITS};
+use crate::{NucleotideError, MAX_BASES};
+
+/// Checks whether two block encoded sequences are equal.
+///
+/// Sequences are equal only if they have the same length and the same bases.
+/// The final block is compared only over the bases it actually holds, so any
+/// bits set beyond the end of the sequence are ignored.
+///
+/// Buffers holding fewer blocks than required for their length are never equal.
+///
+/// # Arguments
+///
+/// * `a` - The first encoded sequence buffer
+/// * `a_len` - The length of the first sequence in bases
+/// * `b` - The second encoded sequence buffer
+/// * `b_len` - The length of the second sequence in bases
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{blocks_eq, encode_alloc};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = encode_alloc(b"ACGTACGTACGTACGTACGTACGTACGTACGTAC")?;
+/// let b = encode_alloc(b"ACGTACGTACGTACGTACGTACGTACGTACGTAC")?;
+/// assert!(blocks_eq(&a, 34, &b, 34));
+/// assert!(!blocks_eq(&a, 34, &b, 33));
+/// # Ok(())
+/// # }
+/// ```
+pub fn blocks_eq(a: &[u64], a_len: usize, b: &[u64], b_len: usize) -> bool {
+    if a_len != b_len {
+        return false;
+    }
+
+    let n_chunks = a_len.div_ceil(32);
+    if a.len() < n_chunks || b.len() < n_chunks {
+        return false;
+    }
+    if n_chunks == 0 {
+        return true;
+    }
+
+    // All full blocks must match exactly
+    let full_chunks = n_chunks - 1;
+    if a[..full_chunks] != b[..full_chunks] {
+        return false;
+    }
+
+    // Compare the final block under the remainder mask
+    let rem = match a_len % 32 {
+        0 => 32,
+        rem => rem,
+    };
+    (a[full_chunks] ^ b[full_chunks]) & window_mask(rem) == 0
+}
+
+/// Lexicographically compares two 2-bit packed sequences of the same length.
+///
+/// Bases are ordered `A < C < G < T` and compared from the first base of the
+/// sequence onward, so the result matches comparing the unpacked ASCII sequences.
+/// Bits beyond `len` are ignored.
+///
+/// # Arguments
+///
+/// * `a` - The first 2-bit packed sequence
+/// * `b` - The second 2-bit packed sequence
+/// * `len` - The number of bases in both sequences
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, cmp_2bit};
+/// use std::cmp::Ordering;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // The integer values order the other way, since the first base is least significant
+/// let a = as_2bit(b"AT")?;
+/// let b = as_2bit(b"CA")?;
+/// assert!(a > b);
+/// assert_eq!(cmp_2bit(a, b, 2)?, Ordering::Less);
+/// # Ok(())
+/// # }
+/// ```
+pub fn cmp_2bit(a: u64, b: u64, len: usize) -> Result<Ordering, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    // Locate the first differing base and compare its codes
+    let diff = (a ^ b) & window_mask(len);
+    if diff == 0 {
+        return Ok(Ordering::Equal);
+    }
+    let shift = (((diff | (diff >> 1)) & LOWER_BITS).trailing_zeros() / 2) * 2;
+    Ok(((a >> shift) & 0b11).cmp(&((b >> shift) & 0b11)))
+}
+
+/// Finds the length of the longest substring shared by two 2-bit packed sequences.
+///
+/// The substrings may occur at any offset in either sequence. Windows of decreasing
+/// length are compared under a mask until a common one is found, which is
+/// inexpensive for sequences that fit in a single u64.
+///
+/// # Arguments
+///
+/// * `a` - The first 2-bit packed sequence
+/// * `a_len` - The number of bases in `a`
+/// * `b` - The second 2-bit packed sequence
+/// * `b_len` - The number of bases in `b`
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if either length is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, longest_common_substring_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = as_2bit(b"ACGTA")?;
+/// let b = as_2bit(b"TACGT")?;
+/// assert_eq!(longest_common_substring_2bit(a, 5, b, 5)?, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn longest_common_substring_2bit(
+    a: u64,
+    a_len: usize,
+    b: u64,
+    b_len: usize,
+) -> Result<usize, NucleotideError> {
+    if a_len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(a_len));
+    }
+    if b_len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(b_len));
+    }
+
+    for len in (1..=a_len.min(b_len)).rev() {
+        let mask = window_mask(len);
+        for i in 0..=(a_len - len) {
+            let window = (a >> (i * 2)) & mask;
+            if (0..=(b_len - len)).any(|j| (b >> (j * 2)) & mask == window) {
+                return Ok(len);
+            }
+        }
+    }
+    Ok(0)
+}
+
+/// Checks whether two 2-bit packed sequences are equal at the positions that matter.
+///
+/// Only positions `i < len` where bit `i` of `care` is set are compared, so
+/// degenerate or unknown positions can be ignored by clearing their bits.
+///
+/// # Arguments
+///
+/// * `a` - The first 2-bit packed sequence
+/// * `b` - The second 2-bit packed sequence
+/// * `len` - The number of bases in both sequences
+/// * `care` - A mask with bit `i` set for each position to compare
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, eq_masked_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = as_2bit(b"ACGT")?;
+/// let b = as_2bit(b"ACTT")?;
+/// assert!(eq_masked_2bit(a, b, 4, 0b1011)?);
+/// assert!(!eq_masked_2bit(a, b, 4, 0b1111)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn eq_masked_2bit(a: u64, b: u64, len: usize, care: u32) -> Result<bool, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    let mask = expand_base_mask(care) & window_mask(len);
+    Ok((a ^ b) & mask == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{as_2bit, encode_alloc};
+
+    #[test]
+    fn test_blocks_eq_non_aligned() {
+        let seq = b"ACTGACTGACTGACTGACTGACTGACTGACTGACTGACTGAC"; // 42 bases
+        let a = encode_alloc(seq).unwrap();
+        let b = encode_alloc(seq).unwrap();
+        assert!(blocks_eq(&a, seq.len(), &b, seq.len()));
+    }
+
+    #[test]
+    fn test_blocks_eq_last_base_differs() {
+        let a = encode_alloc(b"ACTGACTGACTGACTGACTGACTGACTGACTGACTGACTGAC").unwrap();
+        let b = encode_alloc(b"ACTGACTGACTGACTGACTGACTGACTGACTGACTGACTGAG").unwrap();
+        assert!(!blocks_eq(&a, 42, &b, 42));
+    }
+
+    #[test]
+    fn test_blocks_eq_ignores_trailing_bits() {
+        let a = encode_alloc(b"ACTGACTGACTGACTGACTGACTGACTGACTGACTG").unwrap();
+        let mut b = a.clone();
+        b[1] |= 0b11 << 8; // Set bits past the 36th base
+        assert!(blocks_eq(&a, 36, &b, 36));
+    }
+
+    #[test]
+    fn test_blocks_eq_lengths() {
+        let a = encode_alloc(&[b'A'; 32]).unwrap();
+        let b = encode_alloc(&[b'A'; 33]).unwrap();
+        assert!(!blocks_eq(&a, 32, &b, 33));
+        assert!(blocks_eq(&a, 32, &b, 32));
+        assert!(blocks_eq(&[], 0, &[], 0));
+
+        // Buffer too short for the claimed length
+        assert!(!blocks_eq(&a, 33, &b, 33));
+    }
+
+    #[test]
+    fn test_cmp_2bit_matches_ascii() {
+        let seqs: [&[u8]; 6] = [b"ACGT", b"ACGA", b"TAAA", b"AAAT", b"CAAA", b"ACGT"];
+        for a in seqs {
+            for b in seqs {
+                let pa = as_2bit(a).unwrap();
+                let pb = as_2bit(b).unwrap();
+                assert_eq!(cmp_2bit(pa, pb, 4), Ok(a.cmp(b)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cmp_2bit_ignores_trailing_bits() {
+        let a = as_2bit(b"ACG").unwrap();
+        assert_eq!(cmp_2bit(a, a | (0b11 << 6), 3), Ok(Ordering::Equal));
+        assert_eq!(cmp_2bit(a, a, 33), Err(NucleotideError::InvalidLength(33)));
+    }
+
+    #[test]
+    fn test_longest_common_substring() {
+        let a = as_2bit(b"ACGTA").unwrap();
+        let b = as_2bit(b"TACGT").unwrap();
+        assert_eq!(longest_common_substring_2bit(a, 5, b, 5), Ok(4));
+        assert_eq!(longest_common_substring_2bit(b, 5, a, 5), Ok(4));
+
+        let c = as_2bit(b"CCCC").unwrap();
+        assert_eq!(longest_common_substring_2bit(a, 5, c, 4), Ok(1));
+
+        let t = as_2bit(b"TTTT").unwrap();
+        assert_eq!(longest_common_substring_2bit(c, 4, t, 4), Ok(0));
+    }
+
+    #[test]
+    fn test_longest_common_substring_edge_cases() {
+        let a = as_2bit(b"ACGTACGTACGTACGTACGTACGTACGTACGT").unwrap();
+        assert_eq!(longest_common_substring_2bit(a, 32, a, 32), Ok(32));
+        assert_eq!(longest_common_substring_2bit(a, 32, 0, 0), Ok(0));
+        assert_eq!(
+            longest_common_substring_2bit(a, 33, a, 32),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            longest_common_substring_2bit(a, 32, a, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_eq_masked_ignored_position() {
+        let a = as_2bit(b"GATTACA").unwrap();
+        let b = as_2bit(b"GATGACA").unwrap();
+        let care = 0b1110111; // Ignore position 3
+        assert_eq!(eq_masked_2bit(a, b, 7, care), Ok(true));
+        assert_eq!(eq_masked_2bit(a, b, 7, 0b1111111), Ok(false));
+    }
+
+    #[test]
+    fn test_eq_masked_edge_cases() {
+        let a = as_2bit(b"ACG").unwrap();
+        // Care bits beyond len are ignored
+        assert_eq!(eq_masked_2bit(a, a | (0b11 << 6), 3, u32::MAX), Ok(true));
+        assert_eq!(eq_masked_2bit(0, u64::MAX, 32, 0), Ok(true));
+        assert_eq!(
+            eq_masked_2bit(a, a, 33, u32::MAX),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::utils::window_mask;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Concatenates two 2-bit packed sequences into one.
+///
+/// The bases of `b` follow the bases of `a`, so the result is what
+/// [`as_2bit`](crate::as_2bit) would pack for the two sequences joined
+/// together. Bits beyond each length are ignored.
+///
+/// # Arguments
+///
+/// * `a` - A u64 containing the first 2-bit packed sequence
+/// * `a_len` - The number of bases in `a`
+/// * `b` - A u64 containing the second 2-bit packed sequence
+/// * `b_len` - The number of bases in `b`
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `a_len + b_len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, concat_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let joined = concat_2bit(as_2bit(b"GAT")?, 3, as_2bit(b"TACA")?, 4)?;
+/// assert_eq!(joined, as_2bit(b"GATTACA")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn concat_2bit(a: u64, a_len: usize, b: u64, b_len: usize) -> Result<u64, NucleotideError> {
+    let total = a_len.saturating_add(b_len);
+    if total > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(total));
+    }
+    if b_len == 0 {
+        return Ok(a & window_mask(a_len));
+    }
+    Ok((a & window_mask(a_len)) | ((b & window_mask(b_len)) << (a_len * 2)))
+}
+
+/// Fuses two k-mers into a single packed key.
+///
+/// This is [`concat_2bit`] for k-mer keys: the key holds `a` in its first
+/// `a_len` bases and `b` in the following `b_len` bases, so distinct pairs of
+/// k-mers with the same lengths always give distinct keys. The halves are
+/// recovered with [`split_fused`].
+///
+/// # Arguments
+///
+/// * `a` - A u64 containing the first 2-bit packed k-mer
+/// * `a_len` - The number of bases in `a`
+/// * `b` - A u64 containing the second 2-bit packed k-mer
+/// * `b_len` - The number of bases in `b`
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `a_len + b_len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, fuse_kmers, split_fused};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (a, b) = (as_2bit(b"ACGT")?, as_2bit(b"TTGA")?);
+/// let key = fuse_kmers(a, 4, b, 4)?;
+/// assert_eq!(split_fused(key, 4, 4)?, (a, b));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn fuse_kmers(a: u64, a_len: usize, b: u64, b_len: usize) -> Result<u64, NucleotideError> {
+    concat_2bit(a, a_len, b, b_len)
+}
+
+/// Splits a key produced by [`fuse_kmers`] back into its two k-mers.
+///
+/// # Arguments
+///
+/// * `key` - The fused key
+/// * `a_len` - The number of bases in the first k-mer
+/// * `b_len` - The number of bases in the second k-mer
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `a_len + b_len` is greater than 32.
+pub fn split_fused(key: u64, a_len: usize, b_len: usize) -> Result<(u64, u64), NucleotideError> {
+    let total = a_len.saturating_add(b_len);
+    if total > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(total));
+    }
+    let a = key & window_mask(a_len);
+    let b = key.checked_shr((a_len * 2) as u32).unwrap_or(0) & window_mask(b_len);
+    Ok((a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_fuse_split_round_trip() {
+        let a = as_2bit(b"ACGTTGCA").unwrap();
+        let b = as_2bit(b"GGATCCTA").unwrap();
+        let key = fuse_kmers(a, 8, b, 8).unwrap();
+        assert_eq!(key, as_2bit(b"ACGTTGCAGGATCCTA").unwrap());
+        assert_eq!(split_fused(key, 8, 8), Ok((a, b)));
+    }
+
+    #[test]
+    fn test_concat_2bit_edge_cases() {
+        let a = as_2bit(b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA").unwrap();
+        assert_eq!(concat_2bit(a, 32, 0, 0), Ok(a));
+        assert_eq!(concat_2bit(0, 0, a, 32), Ok(a));
+        assert_eq!(split_fused(a, 32, 0), Ok((a, 0)));
+        assert_eq!(split_fused(a, 0, 32), Ok((0, a)));
+
+        // Bits beyond each length are ignored
+        let b = as_2bit(b"TT").unwrap();
+        assert_eq!(
+            concat_2bit(a, 1, b | (1 << 10), 2),
+            Ok(as_2bit(b"ATT").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_concat_2bit_errors() {
+        assert_eq!(
+            concat_2bit(0, 20, 0, 13),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            fuse_kmers(0, 16, 0, 17),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            split_fused(0, 33, 0),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            concat_2bit(0, usize::MAX, 0, 1),
+            Err(NucleotideError::InvalidLength(usize::MAX))
+        );
+    }
+}
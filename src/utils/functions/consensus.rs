@@ -0,0 +1,94 @@
+use super::access::BASES;
+use crate::utils::packing::base_to_2bit;
+use crate::NucleotideError;
+
+/// Counts the bases of one alignment column.
+///
+/// Each byte is the base of one aligned sequence at the column. Gaps (`-`) are
+/// skipped.
+///
+/// # Arguments
+///
+/// * `column` - The ASCII bases of the column (A,C,G,T, case insensitive, or `-`)
+///
+/// # Returns
+///
+/// Returns the `[A, C, G, T]` counts.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if the column contains any characters
+/// other than A,C,G,T (case insensitive) or `-`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::pack_column;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(pack_column(b"AAG-t")?, [2, 0, 1, 1]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn pack_column(column: &[u8]) -> Result<[u32; 4], NucleotideError> {
+    let mut counts = [0u32; 4];
+    for &base in column {
+        if base != b'-' {
+            counts[base_to_2bit(base)? as usize] += 1;
+        }
+    }
+    Ok(counts)
+}
+
+/// Returns the most frequent base of a column profile.
+///
+/// Ties are broken in A, C, G, T order, and an empty profile gives `N`.
+///
+/// # Arguments
+///
+/// * `counts` - The `[A, C, G, T]` counts, as returned by [`pack_column`]
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::consensus_base;
+///
+/// assert_eq!(consensus_base([1, 4, 2, 0]), b'C');
+/// assert_eq!(consensus_base([0, 3, 3, 0]), b'C');
+/// assert_eq!(consensus_base([0, 0, 0, 0]), b'N');
+/// ```
+pub fn consensus_base(counts: [u32; 4]) -> u8 {
+    let (code, &count) = counts
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, count)| count)
+        .unwrap_or((0, &0));
+    if count == 0 {
+        b'N'
+    } else {
+        BASES[code]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_consensus() {
+        let counts = pack_column(b"GGaG-Gc-G").unwrap();
+        assert_eq!(counts, [1, 1, 5, 0]);
+        assert_eq!(consensus_base(counts), b'G');
+    }
+
+    #[test]
+    fn test_column_consensus_edge_cases() {
+        assert_eq!(pack_column(b"--"), Ok([0; 4]));
+        assert_eq!(consensus_base([0; 4]), b'N');
+        assert_eq!(consensus_base([2, 2, 2, 2]), b'A');
+        assert_eq!(consensus_base([0, 0, 1, 1]), b'G');
+        assert_eq!(consensus_base([0, 0, 0, u32::MAX]), b'T');
+        assert_eq!(pack_column(b"AN-"), Err(NucleotideError::InvalidBase(b'N')));
+    }
+}
@@ -0,0 +1,399 @@
+use crate::utils::packing::base_to_2bit;
+use crate::utils::{window_mask, LOWER_BITS};
+use crate::{NucleotideError, MAX_BASES};
+
+/// Counts the 2-bit groups of `packed` equal to `code` within `mask`.
+#[inline(always)]
+fn count_code(packed: u64, code: u64, mask: u64) -> u32 {
+    // Groups matching the code become 00 after the XOR
+    let diff = packed ^ (code * LOWER_BITS);
+    (!(diff | (diff >> 1)) & LOWER_BITS & mask).count_ones()
+}
+
+/// Counts the occurrences of a base across a block encoded sequence.
+///
+/// Each block is counted with a mask and popcount, so the sequence is never unpacked.
+///
+/// # Arguments
+///
+/// * `blocks` - The encoded sequence buffer
+/// * `total_len` - The length of the sequence in bases
+/// * `base` - The nucleotide to count (A,C,G,T, case insensitive)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if `base` is not a nucleotide.
+///
+/// Returns `NucleotideError::InvalidLength` if `blocks` is too short to hold `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{count_base_blocks, encode_alloc};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = encode_alloc(b"ACGTAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA")?;
+/// assert_eq!(count_base_blocks(&blocks, 40, b'A')?, 37);
+/// assert_eq!(count_base_blocks(&blocks, 40, b'T')?, 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn count_base_blocks(
+    blocks: &[u64],
+    total_len: usize,
+    base: u8,
+) -> Result<u32, NucleotideError> {
+    let code = base_to_2bit(base)?;
+    let n_chunks = total_len.div_ceil(32);
+    if blocks.len() < n_chunks {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+
+    let mut count = 0;
+    let mut remaining = total_len;
+    for &block in &blocks[..n_chunks] {
+        count += count_code(block, code, window_mask(remaining));
+        remaining = remaining.saturating_sub(32);
+    }
+    Ok(count)
+}
+
+/// Counts the occurrences of a base in the first `i` positions of a 2-bit packed sequence.
+///
+/// This is the rank operation used by BWT/FM-index style structures. The packed
+/// value is masked to its first `i` bases and the matching groups are popcounted.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `base` - The nucleotide to count (A,C,G,T, case insensitive)
+/// * `i` - The number of leading positions to count over
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if `base` is not a nucleotide.
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// Returns `NucleotideError::IndexOutOfBounds` if `i` is greater than `len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, rank_base_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"AACGA")?;
+/// assert_eq!(rank_base_2bit(packed, b'A', 3, 5)?, 2);
+/// assert_eq!(rank_base_2bit(packed, b'A', 5, 5)?, 3);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rank_base_2bit(packed: u64, base: u8, i: usize, len: usize) -> Result<u32, NucleotideError> {
+    let code = base_to_2bit(base)?;
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if i > len {
+        return Err(NucleotideError::IndexOutOfBounds {
+            index: i,
+            length: len,
+        });
+    }
+    Ok(count_code(packed, code, window_mask(i)))
+}
+
+/// Returns which bases occur in a 2-bit packed sequence.
+///
+/// Each base is counted with a masked popcount and its bit is set when the
+/// count is nonzero. Bits beyond `len` bases are ignored.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence (<= 32)
+///
+/// # Returns
+///
+/// Returns a 4-bit mask with bit 0 set for A, bit 1 for C, bit 2 for G and bit 3 for T.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, present_bases_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(present_bases_2bit(as_2bit(b"GATTAGA")?, 7)?, 0b1101);
+/// # Ok(())
+/// # }
+/// ```
+pub fn present_bases_2bit(packed: u64, len: usize) -> Result<u8, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let mask = window_mask(len);
+    Ok((0..4).fold(0, |present, code| {
+        if count_code(packed, code, mask) > 0 {
+            present | (1 << code)
+        } else {
+            present
+        }
+    }))
+}
+
+/// Counts the bases at each position across equal-length 2-bit packed sequences.
+///
+/// # Arguments
+///
+/// * `seqs` - The 2-bit packed sequences, each holding `len` bases
+/// * `len` - The number of bases in each sequence
+///
+/// # Returns
+///
+/// Returns one `[A, C, G, T]` count per position.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, position_frequency_matrix};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let seqs = [as_2bit(b"AC")?, as_2bit(b"AG")?];
+/// let matrix = position_frequency_matrix(&seqs, 2)?;
+/// assert_eq!(matrix, vec![[2, 0, 0, 0], [0, 1, 1, 0]]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn position_frequency_matrix(
+    seqs: &[u64],
+    len: usize,
+) -> Result<Vec<[u32; 4]>, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let mut matrix = vec![[0u32; 4]; len];
+    for &packed in seqs {
+        for (i, counts) in matrix.iter_mut().enumerate() {
+            counts[((packed >> (i * 2)) & 0b11) as usize] += 1;
+        }
+    }
+    Ok(matrix)
+}
+
+/// Calculates the Shannon entropy at each position across equal-length 2-bit packed sequences.
+///
+/// The counts come from [`position_frequency_matrix`]. Entropy is measured in bits,
+/// so a fully conserved position gives 0.0 and an evenly mixed one gives 2.0.
+///
+/// # Arguments
+///
+/// * `seqs` - The 2-bit packed sequences, each holding `len` bases
+/// * `len` - The number of bases in each sequence
+///
+/// # Returns
+///
+/// Returns one entropy per position, or an empty vector if `seqs` is empty.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, positional_entropy};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let seqs = [as_2bit(b"AA")?, as_2bit(b"AC")?, as_2bit(b"AG")?, as_2bit(b"AT")?];
+/// assert_eq!(positional_entropy(&seqs, 2)?, vec![0.0, 2.0]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn positional_entropy(seqs: &[u64], len: usize) -> Result<Vec<f64>, NucleotideError> {
+    let matrix = position_frequency_matrix(seqs, len)?;
+    if seqs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let total = seqs.len() as f64;
+    Ok(matrix
+        .iter()
+        .map(|counts| {
+            counts
+                .iter()
+                .filter(|&&count| count > 0)
+                .map(|&count| {
+                    let p = count as f64 / total;
+                    -p * p.log2()
+                })
+                .sum::<f64>()
+                // Avoid reporting -0.0 for conserved positions
+                .abs()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{as_2bit, encode_alloc};
+
+    #[test]
+    fn test_count_base_blocks() {
+        let seq = b"AACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGA"; // 40 bases
+        let blocks = encode_alloc(seq).unwrap();
+
+        for base in [b'A', b'C', b'G', b'T'] {
+            let expected = seq.iter().filter(|&&b| b == base).count() as u32;
+            assert_eq!(count_base_blocks(&blocks, seq.len(), base), Ok(expected));
+        }
+        assert_eq!(count_base_blocks(&blocks, seq.len(), b'a'), Ok(17));
+    }
+
+    #[test]
+    fn test_count_base_blocks_ignores_padding() {
+        // Padding bits in the final block decode as A
+        let blocks = encode_alloc(b"TTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTT").unwrap();
+        assert_eq!(count_base_blocks(&blocks, 34, b'A'), Ok(0));
+        assert_eq!(count_base_blocks(&blocks, 34, b'T'), Ok(34));
+    }
+
+    #[test]
+    fn test_count_base_blocks_errors() {
+        let blocks = encode_alloc(b"ACGT").unwrap();
+        assert_eq!(
+            count_base_blocks(&blocks, 4, b'N'),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        assert_eq!(
+            count_base_blocks(&blocks, 33, b'A'),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(count_base_blocks(&[], 0, b'A'), Ok(0));
+    }
+
+    #[test]
+    fn test_rank_base_2bit() {
+        let packed = as_2bit(b"AACGA").unwrap();
+        let expected = [0, 1, 2, 2, 2, 3];
+        for (i, &rank) in expected.iter().enumerate() {
+            assert_eq!(rank_base_2bit(packed, b'A', i, 5), Ok(rank));
+        }
+        assert_eq!(rank_base_2bit(packed, b'g', 4, 5), Ok(1));
+    }
+
+    #[test]
+    fn test_rank_base_2bit_errors() {
+        assert_eq!(
+            rank_base_2bit(0, b'N', 1, 4),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        assert_eq!(
+            rank_base_2bit(0, b'A', 5, 4),
+            Err(NucleotideError::IndexOutOfBounds {
+                index: 5,
+                length: 4
+            })
+        );
+        assert_eq!(
+            rank_base_2bit(0, b'A', 0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_position_frequency_matrix() {
+        let seqs = [
+            as_2bit(b"ACGTA").unwrap(),
+            as_2bit(b"ACGGC").unwrap(),
+            as_2bit(b"TCATG").unwrap(),
+        ];
+        let matrix = position_frequency_matrix(&seqs, 5).unwrap();
+        assert_eq!(
+            matrix,
+            vec![
+                [2, 0, 0, 1],
+                [0, 3, 0, 0],
+                [1, 0, 2, 0],
+                [0, 0, 1, 2],
+                [1, 1, 1, 0],
+            ]
+        );
+        assert!(matrix.iter().all(|counts| counts.iter().sum::<u32>() == 3));
+    }
+
+    #[test]
+    fn test_position_frequency_matrix_edge_cases() {
+        assert_eq!(position_frequency_matrix(&[], 3), Ok(vec![[0; 4]; 3]));
+        assert_eq!(
+            position_frequency_matrix(&[u64::MAX], 32),
+            Ok(vec![[0, 0, 0, 1]; 32])
+        );
+        assert_eq!(
+            position_frequency_matrix(&[0], 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_present_bases_2bit() {
+        assert_eq!(
+            present_bases_2bit(as_2bit(b"ACAC").unwrap(), 4).unwrap(),
+            0b0011
+        );
+        assert_eq!(
+            present_bases_2bit(as_2bit(b"ACGT").unwrap(), 4).unwrap(),
+            0b1111
+        );
+        assert_eq!(
+            present_bases_2bit(as_2bit(b"TTTT").unwrap(), 4).unwrap(),
+            0b1000
+        );
+        assert_eq!(present_bases_2bit(u64::MAX, 0).unwrap(), 0);
+
+        // Stray bits past the window are not counted
+        assert_eq!(present_bases_2bit(u64::MAX << 8, 4).unwrap(), 0b0001);
+        assert_eq!(
+            present_bases_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_positional_entropy() {
+        // Conserved at positions 0 and 3, two-way split at 1, uniform at 2
+        let seqs: Vec<u64> = [b"GAAC", b"GCCC", b"GAGC", b"GCTC"]
+            .iter()
+            .map(|seq| as_2bit(*seq).unwrap())
+            .collect();
+        let entropy = positional_entropy(&seqs, 4).unwrap();
+        assert_eq!(entropy.len(), 4);
+        assert_eq!(entropy[0], 0.0);
+        assert!((entropy[1] - 1.0).abs() < 1e-12);
+        assert!((entropy[2] - 2.0).abs() < 1e-12);
+        assert_eq!(entropy[3], 0.0);
+        assert!(entropy[0].is_sign_positive());
+    }
+
+    #[test]
+    fn test_positional_entropy_edge_cases() {
+        assert!(positional_entropy(&[], 4).unwrap().is_empty());
+        assert!(positional_entropy(&[0], 0).unwrap().is_empty());
+        assert_eq!(positional_entropy(&[0], 2).unwrap(), vec![0.0, 0.0]);
+        assert_eq!(
+            positional_entropy(&[], 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use super::access::base_at_2bit;
+use crate::utils::{window_mask, LOWER_BITS};
+use crate::{NucleotideError, MAX_BASES};
+
+/// Lists the mismatching bases between two 2-bit packed sequences.
+///
+/// Each mismatch is reported as `(position, a_base, b_base)` with both bases
+/// given as ASCII nucleotides, in ascending order of position.
+///
+/// # Arguments
+///
+/// * `a` - The first 2-bit packed sequence
+/// * `b` - The second 2-bit packed sequence
+/// * `len` - The number of bases in both sequences
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, diff_bases_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = as_2bit(b"ACGT")?;
+/// let b = as_2bit(b"ACTT")?;
+/// assert_eq!(diff_bases_2bit(a, b, 4)?, vec![(2, b'G', b'T')]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn diff_bases_2bit(
+    a: u64,
+    b: u64,
+    len: usize,
+) -> Result<Vec<(usize, u8, u8)>, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    // Collapse each differing 2-bit group onto its lower bit
+    let diff = (a ^ b) & window_mask(len);
+    let mut collapsed = (diff | (diff >> 1)) & LOWER_BITS;
+
+    let mut mismatches = Vec::with_capacity(collapsed.count_ones() as usize);
+    while collapsed != 0 {
+        let pos = collapsed.trailing_zeros() as usize / 2;
+        mismatches.push((pos, base_at_2bit(a, len, pos)?, base_at_2bit(b, len, pos)?));
+        collapsed &= collapsed - 1;
+    }
+    Ok(mismatches)
+}
+
+/// Counts the transitions and transversions between two packed blocks within `mask`.
+#[inline(always)]
+fn ts_tv_masked(a: u64, b: u64, mask: u64) -> (u32, u32) {
+    // Transitions (A<->G, C<->T) flip only the high bit of a group, while
+    // transversions always flip the low bit
+    let diff = (a ^ b) & mask;
+    let transitions = (diff >> 1) & !diff & LOWER_BITS;
+    let transversions = diff & LOWER_BITS;
+    (transitions.count_ones(), transversions.count_ones())
+}
+
+/// Counts the transitions and transversions between two aligned block encoded sequences.
+///
+/// Transitions are purine to purine (A<->G) or pyrimidine to pyrimidine (C<->T)
+/// substitutions, and transversions are every other substitution. Unused bits
+/// of the final block are ignored.
+///
+/// # Arguments
+///
+/// * `a` - The first encoded sequence buffer
+/// * `b` - The second encoded sequence buffer
+/// * `total_len` - The aligned length of both sequences in bases
+///
+/// # Returns
+///
+/// Returns `(transitions, transversions)`.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if the buffers differ in length or
+/// are too short to hold `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{encode_alloc, ts_tv_blocks};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = encode_alloc(b"ACGTACGT")?;
+/// let b = encode_alloc(b"GCGAACGT")?;
+/// assert_eq!(ts_tv_blocks(&a, &b, 8)?, (1, 1));
+/// # Ok(())
+/// # }
+/// ```
+pub fn ts_tv_blocks(a: &[u64], b: &[u64], total_len: usize) -> Result<(u64, u64), NucleotideError> {
+    if a.len() != b.len() {
+        return Err(NucleotideError::InvalidLength(b.len()));
+    }
+    let n_chunks = total_len.div_ceil(32);
+    if a.len() < n_chunks {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+
+    let mut transitions = 0;
+    let mut transversions = 0;
+    let mut remaining = total_len;
+    for (&x, &y) in a.iter().zip(b).take(n_chunks) {
+        let (ts, tv) = ts_tv_masked(x, y, window_mask(remaining));
+        transitions += ts as u64;
+        transversions += tv as u64;
+        remaining = remaining.saturating_sub(32);
+    }
+    Ok((transitions, transversions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{as_2bit, encode_alloc};
+
+    #[test]
+    fn test_diff_bases_two_substitutions() {
+        let a = as_2bit(b"ACGTACGTAC").unwrap();
+        let b = as_2bit(b"AGGTACGTAT").unwrap();
+        assert_eq!(
+            diff_bases_2bit(a, b, 10),
+            Ok(vec![(1, b'C', b'G'), (9, b'C', b'T')])
+        );
+    }
+
+    #[test]
+    fn test_diff_bases_ignores_trailing_bits() {
+        let a = as_2bit(b"ACGT").unwrap();
+        let b = a | (0b11 << 8);
+        assert_eq!(diff_bases_2bit(a, b, 4), Ok(vec![]));
+        assert_eq!(
+            diff_bases_2bit(a, b, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_ts_tv_blocks() {
+        let a = b"ACGTACGTACGTACGTACGTACGTACGTACGTACGTACGT".to_vec();
+        let mut b = a.clone();
+        // Transitions at 0 (A->G), 1 (C->T) and 35 (T->C)
+        b[0] = b'G';
+        b[1] = b'T';
+        b[35] = b'C';
+        // Transversions at 2 (G->C), 30 (G->T) and 39 (T->A)
+        b[2] = b'C';
+        b[30] = b'T';
+        b[39] = b'A';
+
+        let x = encode_alloc(&a).unwrap();
+        let y = encode_alloc(&b).unwrap();
+        assert_eq!(ts_tv_blocks(&x, &y, 40), Ok((3, 3)));
+        assert_eq!(ts_tv_blocks(&x, &x, 40), Ok((0, 0)));
+        assert_eq!(ts_tv_blocks(&x, &y, 32), Ok((2, 2)));
+    }
+
+    #[test]
+    fn test_ts_tv_blocks_errors() {
+        let x = encode_alloc(&[b'A'; 40]).unwrap();
+        // Garbage past the aligned length is ignored
+        let y = vec![x[0], x[1] | (u64::MAX << 16)];
+        assert_eq!(ts_tv_blocks(&x, &y, 40), Ok((0, 0)));
+
+        assert_eq!(
+            ts_tv_blocks(&x, &y[..1], 32),
+            Err(NucleotideError::InvalidLength(1))
+        );
+        assert_eq!(
+            ts_tv_blocks(&x, &y, 65),
+            Err(NucleotideError::InvalidLength(65))
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{NucleotideError, MAX_BASES};
+
+/// Computes the edit distance between two 2-bit packed sequences within a band.
+///
+/// Only alignment cells within `band` positions of the main diagonal are
+/// filled, which is exact for any distance up to `band` since an alignment with
+/// at most `band` edits never leaves the band. Substitutions, insertions and
+/// deletions each cost one.
+///
+/// # Arguments
+///
+/// * `a` - A u64 containing the first 2-bit packed sequence
+/// * `a_len` - The number of bases in `a`
+/// * `b` - A u64 containing the second 2-bit packed sequence
+/// * `b_len` - The number of bases in `b`
+/// * `band` - The largest diagonal offset, and so the largest distance, considered
+///
+/// # Returns
+///
+/// Returns the edit distance, or `None` if it is greater than `band`.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if either length is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, levenshtein_banded_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = as_2bit(b"ACGTACGT")?;
+/// let b = as_2bit(b"ACGACGTT")?;
+/// assert_eq!(levenshtein_banded_2bit(a, 8, b, 8, 2)?, Some(2));
+/// assert_eq!(levenshtein_banded_2bit(a, 8, b, 8, 1)?, None);
+/// # Ok(())
+/// # }
+/// ```
+pub fn levenshtein_banded_2bit(
+    a: u64,
+    a_len: usize,
+    b: u64,
+    b_len: usize,
+    band: usize,
+) -> Result<Option<u32>, NucleotideError> {
+    if a_len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(a_len));
+    }
+    if b_len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(b_len));
+    }
+    if a_len.abs_diff(b_len) > band {
+        return Ok(None);
+    }
+
+    // Cells outside the band are never reached by an alignment within it
+    const OUTSIDE: u32 = u32::MAX / 2;
+    let mut prev = [OUTSIDE; 33];
+    let mut curr = [OUTSIDE; 33];
+    for (j, cell) in prev.iter_mut().enumerate().take(b_len.min(band) + 1) {
+        *cell = j as u32;
+    }
+
+    for i in 1..=a_len {
+        let a_code = (a >> ((i - 1) * 2)) & 0b11;
+        let lo = i.saturating_sub(band);
+        let hi = i.saturating_add(band).min(b_len);
+
+        curr.fill(OUTSIDE);
+        if lo == 0 {
+            curr[0] = i as u32;
+        }
+        for j in lo.max(1)..=hi {
+            let b_code = (b >> ((j - 1) * 2)) & 0b11;
+            let substitution = prev[j - 1] + u32::from(a_code != b_code);
+            curr[j] = substitution.min(prev[j] + 1).min(curr[j - 1] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let distance = prev[b_len];
+    Ok((distance as usize <= band).then_some(distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    /// Full dynamic programming edit distance over ASCII sequences
+    fn naive_levenshtein(a: &[u8], b: &[u8]) -> u32 {
+        let mut prev: Vec<u32> = (0..=b.len() as u32).collect();
+        for (i, &x) in a.iter().enumerate() {
+            let mut curr = vec![i as u32 + 1; b.len() + 1];
+            for (j, &y) in b.iter().enumerate() {
+                curr[j + 1] = (prev[j] + u32::from(x != y))
+                    .min(prev[j + 1] + 1)
+                    .min(curr[j] + 1);
+            }
+            prev = curr;
+        }
+        prev[b.len()]
+    }
+
+    #[test]
+    fn test_levenshtein_banded_within_band() {
+        let pairs: [(&[u8], &[u8]); 5] = [
+            (b"ACGTACGT", b"ACGTACGT"),
+            (b"ACGTACGT", b"ACGAACGT"),
+            (b"ACGTACGT", b"ACGTCGT"),
+            (b"GATTACA", b"GCATTACAT"),
+            (b"", b"ACG"),
+        ];
+        for (x, y) in pairs {
+            let expected = naive_levenshtein(x, y);
+            let (a, b) = (as_2bit(x).unwrap(), as_2bit(y).unwrap());
+            assert_eq!(
+                levenshtein_banded_2bit(a, x.len(), b, y.len(), 3),
+                Ok(Some(expected))
+            );
+        }
+    }
+
+    #[test]
+    fn test_levenshtein_banded_beyond_band() {
+        let x = b"AAAAAAAAAAAAAAAA";
+        let y = b"AAAATTTTAAAAAAAA";
+        let (a, b) = (as_2bit(x).unwrap(), as_2bit(y).unwrap());
+        assert_eq!(naive_levenshtein(x, y), 4);
+        assert_eq!(levenshtein_banded_2bit(a, 16, b, 16, 3), Ok(None));
+        assert_eq!(levenshtein_banded_2bit(a, 16, b, 16, 4), Ok(Some(4)));
+
+        // The length difference alone exceeds the band
+        assert_eq!(levenshtein_banded_2bit(a, 16, a, 12, 3), Ok(None));
+    }
+
+    #[test]
+    fn test_levenshtein_banded_full_length() {
+        let x = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA";
+        let y = b"CGTTGCAACGTTGCAACGTTGCAACGTTGCAA";
+        let (a, b) = (as_2bit(x).unwrap(), as_2bit(y).unwrap());
+        assert_eq!(naive_levenshtein(x, y), 2);
+        assert_eq!(levenshtein_banded_2bit(a, 32, b, 32, 2), Ok(Some(2)));
+        assert_eq!(
+            levenshtein_banded_2bit(a, 32, b, 32, usize::MAX),
+            Ok(Some(2))
+        );
+    }
+
+    #[test]
+    fn test_levenshtein_banded_errors() {
+        assert_eq!(
+            levenshtein_banded_2bit(0, 33, 0, 4, 2),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            levenshtein_banded_2bit(0, 4, 0, 33, 2),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::utils::window_mask;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Serializes a 2-bit packed sequence in big-endian (network) byte order.
+///
+/// Bits beyond `len` are cleared before serializing, so two packed values
+/// holding the same bases always produce the same bytes. The last byte holds
+/// the first four bases, with the first base in its two least significant bits,
+/// regardless of the endianness of the machine.
+///
+/// Lengths greater than 32 keep all 64 bits.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, to_bits_be};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACGT")? | (0b11 << 8); // Stray bits past the sequence
+/// assert_eq!(to_bits_be(packed, 4), [0, 0, 0, 0, 0, 0, 0, 0b11100100]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_bits_be(packed: u64, len: usize) -> [u8; 8] {
+    (packed & window_mask(len)).to_be_bytes()
+}
+
+/// Deserializes a 2-bit packed sequence written by [`to_bits_be`].
+///
+/// Bits beyond `len` are cleared in the result.
+///
+/// # Arguments
+///
+/// * `bytes` - The big-endian serialized packed value
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, from_bits_be, to_bits_be};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"GATTACA")?;
+/// assert_eq!(from_bits_be(to_bits_be(packed, 7), 7)?, packed);
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_bits_be(bytes: [u8; 8], len: usize) -> Result<u64, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    Ok(u64::from_be_bytes(bytes) & window_mask(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_bits_be_round_trip() {
+        let seq = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA";
+        for len in 0..=seq.len() {
+            let packed = as_2bit(&seq[..len]).unwrap();
+            assert_eq!(from_bits_be(to_bits_be(packed, len), len), Ok(packed));
+        }
+    }
+
+    #[test]
+    fn test_bits_be_masks_unused_bits() {
+        let packed = as_2bit(b"ACG").unwrap();
+        let dirty = packed | (u64::MAX << 6);
+        assert_eq!(to_bits_be(dirty, 3), to_bits_be(packed, 3));
+        assert_eq!(from_bits_be(dirty.to_be_bytes(), 3), Ok(packed));
+        assert_eq!(to_bits_be(dirty, 3), packed.to_be_bytes());
+    }
+
+    #[test]
+    fn test_bits_be_byte_order() {
+        let packed = as_2bit(b"TAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAC").unwrap();
+        let bytes = to_bits_be(packed, 32);
+        assert_eq!(bytes[7], 0b11);
+        assert_eq!(bytes[0], 0b01 << 6);
+        assert_eq!(
+            from_bits_be(bytes, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,454 @@
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+use std::arch::x86_64::*;
+
+use crate::utils::packing::base_to_2bit;
+use crate::utils::{window_mask, LOWER_BITS};
+use crate::{as_2bit, NucleotideError, MAX_BASES};
+
+/// Counts the number of G or C bases in a 2-bit encoded u64 value.
+///
+/// G (10) and C (01) are the only codes whose two bits differ, so the count is
+/// the popcount of the XOR of each base's upper and lower bit.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence (<= 32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, gc_count_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACGTGG")?;
+/// assert_eq!(gc_count_2bit(packed, 6)?, 4);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn gc_count_2bit(packed: u64, len: usize) -> Result<u32, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if len == 0 {
+        return Ok(0);
+    }
+
+    let mask = window_mask(len);
+
+    let gc_bits = (packed ^ (packed >> 1)) & LOWER_BITS & mask;
+    Ok(gc_bits.count_ones())
+}
+
+/// Calculates the fraction of G or C bases in a 2-bit encoded u64 value.
+///
+/// An empty sequence has a GC fraction of `0.0`.
+/// Use [`gc_fraction_2bit_strict`] to treat empty input as an error instead.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence (<= 32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, gc_fraction_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACGT")?;
+/// assert_eq!(gc_fraction_2bit(packed, 4)?, 0.5);
+/// assert_eq!(gc_fraction_2bit(0, 0)?, 0.0);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn gc_fraction_2bit(packed: u64, len: usize) -> Result<f64, NucleotideError> {
+    let gc_count = gc_count_2bit(packed, len)?;
+    if len == 0 {
+        return Ok(0.0);
+    }
+    Ok(gc_count as f64 / len as f64)
+}
+
+/// Calculates the fraction of G or C bases in a 2-bit encoded u64 value,
+/// rejecting empty input.
+///
+/// This is identical to [`gc_fraction_2bit`] except that an empty sequence
+/// is reported as an error rather than silently returning `0.0`.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence (1..=32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::EmptySequence` if `len` is 0.
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{gc_fraction_2bit_strict, NucleotideError};
+///
+/// assert_eq!(gc_fraction_2bit_strict(0, 0), Err(NucleotideError::EmptySequence));
+/// ```
+#[inline]
+pub fn gc_fraction_2bit_strict(packed: u64, len: usize) -> Result<f64, NucleotideError> {
+    if len == 0 {
+        return Err(NucleotideError::EmptySequence);
+    }
+    gc_fraction_2bit(packed, len)
+}
+
+/// Calculates the GC fraction of each consecutive non-overlapping window of a sequence.
+///
+/// Windows are taken at offsets `0, window, 2 * window, ...`.
+/// If the sequence length is not a multiple of `window`, the trailing partial
+/// window is kept and its GC fraction is computed over its actual length.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `window` - The size of each window in bases (1..=32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `window` is 0 or greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::sliding_gc;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let gc = sliding_gc(b"GGCCAATTGC", 4)?;
+/// assert_eq!(gc, vec![1.0, 0.0, 1.0]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn sliding_gc(seq: &[u8], window: usize) -> Result<Vec<f64>, NucleotideError> {
+    if window == 0 || window > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(window));
+    }
+
+    seq.chunks(window)
+        .map(|chunk| {
+            let packed = as_2bit(chunk)?;
+            gc_fraction_2bit(packed, chunk.len())
+        })
+        .collect()
+}
+
+/// Iterates over the GC fraction of every overlapping window of a nucleotide sequence.
+///
+/// Windows advance one base at a time and the GC count is updated as bases
+/// enter and leave the window, so each step is constant time. A sequence
+/// shorter than `window` yields nothing.
+///
+/// The whole sequence is validated before the iterator is returned.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `window` - The size of each window in bases (1..=32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `window` is 0 or greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::rolling_gc;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let gc: Vec<f64> = rolling_gc(b"GGAAT", 2)?.collect();
+/// assert_eq!(gc, vec![1.0, 0.5, 0.0, 0.0]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rolling_gc(
+    seq: &[u8],
+    window: usize,
+) -> Result<impl Iterator<Item = f64> + '_, NucleotideError> {
+    if window == 0 || window > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(window));
+    }
+    for &base in seq {
+        base_to_2bit(base)?;
+    }
+
+    // C (01) and G (10) are the codes whose two bits differ
+    let is_gc = |base: u8| {
+        // Validated above
+        let code = base_to_2bit(base).unwrap_or_default();
+        ((code ^ (code >> 1)) & 1) as u32
+    };
+
+    let mut gc_count = 0;
+    let fractions = seq.iter().enumerate().filter_map(move |(i, &base)| {
+        gc_count += is_gc(base);
+        if i >= window {
+            gc_count -= is_gc(seq[i - window]);
+        }
+        (i + 1 >= window).then(|| gc_count as f64 / window as f64)
+    });
+    Ok(fractions)
+}
+
+/// Counts the G or C bases of full blocks, four blocks at a time.
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+#[inline]
+unsafe fn gc_count_full_blocks_avx2(blocks: &[u64]) -> u64 {
+    let lower_bits = _mm256_set1_epi64x(LOWER_BITS as i64);
+
+    let mut total = 0u64;
+    let mut chunks = blocks.chunks_exact(4);
+    for chunk in &mut chunks {
+        let v = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+        let gc_bits = _mm256_and_si256(_mm256_xor_si256(v, _mm256_srli_epi64(v, 1)), lower_bits);
+
+        total += (_mm256_extract_epi64(gc_bits, 0) as u64).count_ones() as u64
+            + (_mm256_extract_epi64(gc_bits, 1) as u64).count_ones() as u64
+            + (_mm256_extract_epi64(gc_bits, 2) as u64).count_ones() as u64
+            + (_mm256_extract_epi64(gc_bits, 3) as u64).count_ones() as u64;
+    }
+
+    // Handle remaining full blocks
+    total + gc_count_full_blocks_scalar(chunks.remainder())
+}
+
+/// Counts the G or C bases of full blocks.
+#[inline]
+fn gc_count_full_blocks_scalar(blocks: &[u64]) -> u64 {
+    blocks
+        .iter()
+        .map(|&block| ((block ^ (block >> 1)) & LOWER_BITS).count_ones() as u64)
+        .sum()
+}
+
+/// Counts the number of G or C bases across a block encoded sequence.
+///
+/// Each block is counted with the same popcount trick as [`gc_count_2bit`], with
+/// the final block masked to the bases it actually holds. Full blocks are
+/// processed several at a time with SIMD when available.
+///
+/// # Arguments
+///
+/// * `blocks` - The encoded sequence buffer
+/// * `total_len` - The length of the sequence in bases
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `blocks` is too short to hold `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{encode_alloc, gc_count_blocks};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = encode_alloc(b"GCGCGCGCGCGCGCGCGCGCGCGCGCGCGCGCATAT")?;
+/// assert_eq!(gc_count_blocks(&blocks, 36)?, 32);
+/// # Ok(())
+/// # }
+/// ```
+pub fn gc_count_blocks(blocks: &[u64], total_len: usize) -> Result<u64, NucleotideError> {
+    let n_chunks = total_len.div_ceil(32);
+    if blocks.len() < n_chunks {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+    if n_chunks == 0 {
+        return Ok(0);
+    }
+
+    // The final block is always masked to its remainder
+    let full_chunks = &blocks[..n_chunks - 1];
+    let rem = match total_len % 32 {
+        0 => 32,
+        rem => rem,
+    };
+    let last = gc_count_2bit(blocks[n_chunks - 1], rem)? as u64;
+
+    #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+    if is_x86_feature_detected!("avx2") {
+        return Ok(unsafe { gc_count_full_blocks_avx2(full_chunks) } + last);
+    }
+
+    Ok(gc_count_full_blocks_scalar(full_chunks) + last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gc_count() {
+        let test_cases: Vec<(&[u8], u32)> = vec![
+            (b"AAAA", 0),
+            (b"TTTT", 0),
+            (b"CCCC", 4),
+            (b"GGGG", 4),
+            (b"ACGT", 2),
+            (b"ACGTACGTACGTACGTACGTACGTACGTACGT", 16),
+        ];
+
+        for (seq, expected) in test_cases {
+            let packed = as_2bit(seq).unwrap();
+            assert_eq!(gc_count_2bit(packed, seq.len()), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_gc_count_ignores_unused_bits() {
+        // "AC" followed by garbage bits that would otherwise count as GC
+        let packed = as_2bit(b"AC").unwrap() | (0b0110 << 4);
+        assert_eq!(gc_count_2bit(packed, 2), Ok(1));
+    }
+
+    #[test]
+    fn test_gc_invalid_length() {
+        assert_eq!(
+            gc_count_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            gc_fraction_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_gc_fraction() {
+        let packed = as_2bit(b"ACGTA").unwrap();
+        assert_eq!(gc_fraction_2bit(packed, 5), Ok(0.4));
+        assert_eq!(gc_fraction_2bit_strict(packed, 5), Ok(0.4));
+    }
+
+    #[test]
+    fn test_gc_fraction_empty() {
+        assert_eq!(gc_fraction_2bit(0, 0), Ok(0.0));
+        assert_eq!(
+            gc_fraction_2bit_strict(0, 0),
+            Err(NucleotideError::EmptySequence)
+        );
+    }
+
+    #[test]
+    fn test_sliding_gc() {
+        let seq = b"GCGCGCGCATATATATGGCCGGCCTTAATTAA";
+        assert_eq!(sliding_gc(seq, 8), Ok(vec![1.0, 0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_sliding_gc_partial_window() {
+        let seq = b"GCGCATATGCA";
+        let observed = sliding_gc(seq, 4).unwrap();
+        assert_eq!(observed.len(), 3);
+        assert_eq!(observed[2], 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_sliding_gc_invalid_window() {
+        assert_eq!(
+            sliding_gc(b"ACGT", 0),
+            Err(NucleotideError::InvalidLength(0))
+        );
+        assert_eq!(
+            sliding_gc(b"ACGT", 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            sliding_gc(b"ACNT", 2),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+
+    #[test]
+    fn test_gc_count_blocks() {
+        let seq: Vec<u8> = (0..100)
+            .map(|i| b"ACGTTGCAAG"[(i * 7 + i / 3) % 10])
+            .collect();
+        let blocks = crate::encode_alloc(&seq).unwrap();
+        let expected = seq.iter().filter(|&&b| b == b'G' || b == b'C').count() as u64;
+        assert_eq!(gc_count_blocks(&blocks, seq.len()), Ok(expected));
+    }
+
+    #[test]
+    fn test_gc_count_blocks_lengths() {
+        // Long enough to cover several groups of four full blocks
+        let seq: Vec<u8> = (0..300)
+            .map(|i| b"ACGTTGCAAG"[(i * 7 + i / 3) % 10])
+            .collect();
+        let blocks = crate::encode_alloc(&seq).unwrap();
+
+        // Compare against the unpacked sequence at every length
+        for len in 1..=seq.len() {
+            let mut dbuf = Vec::new();
+            crate::decode(&blocks, len, &mut dbuf).unwrap();
+            let expected = dbuf.iter().filter(|&&b| b == b'G' || b == b'C').count() as u64;
+            assert_eq!(
+                gc_count_blocks(&blocks, len),
+                Ok(expected),
+                "Failed at {}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn test_gc_count_blocks_edge_cases() {
+        assert_eq!(gc_count_blocks(&[], 0), Ok(0));
+        assert_eq!(gc_count_blocks(&[u64::MAX], 0), Ok(0));
+        assert_eq!(
+            gc_count_blocks(&[0], 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_rolling_gc_matches_per_window() {
+        let seq = b"ACGTTGCAAGGCTTAACCGGTATATCGCGATTTGACCAGTGGGCCCAAATTT";
+        for window in [1, 3, 8, 32] {
+            let expected: Vec<f64> = seq
+                .windows(window)
+                .map(|w| gc_fraction_2bit(as_2bit(w).unwrap(), window).unwrap())
+                .collect();
+            let observed: Vec<f64> = rolling_gc(seq, window).unwrap().collect();
+            assert_eq!(observed, expected, "Failed for window {}", window);
+        }
+    }
+
+    #[test]
+    fn test_rolling_gc_edge_cases() {
+        assert_eq!(rolling_gc(b"ACG", 4).unwrap().count(), 0);
+        assert!(matches!(
+            rolling_gc(b"ACGT", 0),
+            Err(NucleotideError::InvalidLength(0))
+        ));
+        assert!(matches!(
+            rolling_gc(b"ACGT", 33),
+            Err(NucleotideError::InvalidLength(33))
+        ));
+        assert!(matches!(
+            rolling_gc(b"ACNT", 2),
+            Err(NucleotideError::InvalidBase(b'N'))
+        ));
+    }
+}
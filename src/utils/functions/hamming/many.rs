@@ -0,0 +1,199 @@
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+use std::arch::x86_64::*;
+
+use crate::utils::{window_mask, LOWER_BITS};
+use crate::{NucleotideError, MAX_BASES};
+
+/// Hamming distance between two packed values already restricted to `mask`
+#[inline(always)]
+pub(crate) fn masked_distance(query: u64, candidate: u64, mask: u64) -> u32 {
+    let diff = (query ^ candidate) & mask;
+    ((diff | (diff >> 1)) & LOWER_BITS).count_ones()
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+#[inline]
+unsafe fn hamming_against_many_avx2(query: u64, candidates: &[u64], mask: u64, out: &mut Vec<u32>) {
+    // Process 4 candidates at a time using AVX2
+    let query_vec = _mm256_set1_epi64x(query as i64);
+    let mask_vec = _mm256_set1_epi64x(mask as i64);
+    let lower_bits = _mm256_set1_epi64x(LOWER_BITS as i64);
+
+    let mut chunks = candidates.chunks_exact(4);
+    for chunk in &mut chunks {
+        let cand_vec = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+
+        // XOR to find differences and collapse each 2-bit group onto its lower bit
+        let diff = _mm256_and_si256(_mm256_xor_si256(query_vec, cand_vec), mask_vec);
+        let combined = _mm256_and_si256(
+            _mm256_or_si256(diff, _mm256_srli_epi64(diff, 1)),
+            lower_bits,
+        );
+
+        out.push((_mm256_extract_epi64(combined, 0) as u64).count_ones());
+        out.push((_mm256_extract_epi64(combined, 1) as u64).count_ones());
+        out.push((_mm256_extract_epi64(combined, 2) as u64).count_ones());
+        out.push((_mm256_extract_epi64(combined, 3) as u64).count_ones());
+    }
+
+    // Handle remaining candidates
+    out.extend(
+        chunks
+            .remainder()
+            .iter()
+            .map(|&candidate| masked_distance(query, candidate, mask)),
+    );
+}
+
+/// Calculates the hamming distance between one 2-bit packed query and many candidates.
+///
+/// All values are assumed to hold `len` bases. Bits beyond `len` are ignored.
+///
+/// # Arguments
+///
+/// * `query` - The 2-bit packed query sequence
+/// * `candidates` - The 2-bit packed candidate sequences
+/// * `len` - The number of bases in the query and each candidate
+///
+/// # Returns
+///
+/// Returns the distance from `query` to each candidate, in candidate order.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, hamming_against_many};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let query = as_2bit(b"ACGT")?;
+/// let candidates = [as_2bit(b"ACGT")?, as_2bit(b"ACGA")?, as_2bit(b"TGCA")?];
+/// assert_eq!(hamming_against_many(query, &candidates, 4)?, vec![0, 1, 4]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn hamming_against_many(
+    query: u64,
+    candidates: &[u64],
+    len: usize,
+) -> Result<Vec<u32>, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    let mask = window_mask(len);
+    let mut distances = Vec::with_capacity(candidates.len());
+
+    #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+    if is_x86_feature_detected!("avx2") {
+        unsafe { hamming_against_many_avx2(query, candidates, mask, &mut distances) };
+        return Ok(distances);
+    }
+
+    distances.extend(
+        candidates
+            .iter()
+            .map(|&candidate| masked_distance(query, candidate, mask)),
+    );
+    Ok(distances)
+}
+
+/// Finds the candidate closest to a 2-bit packed query by hamming distance.
+///
+/// Ties are resolved in favor of the earliest candidate.
+///
+/// # Arguments
+///
+/// * `query` - The 2-bit packed query sequence
+/// * `candidates` - The 2-bit packed candidate sequences
+/// * `len` - The number of bases in the query and each candidate
+///
+/// # Returns
+///
+/// Returns the index and distance of the closest candidate, or `None` if there are no candidates.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, nearest_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let query = as_2bit(b"ACGT")?;
+/// let candidates = [as_2bit(b"TGCA")?, as_2bit(b"ACGA")?];
+/// assert_eq!(nearest_2bit(query, &candidates, 4)?, Some((1, 1)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn nearest_2bit(
+    query: u64,
+    candidates: &[u64],
+    len: usize,
+) -> Result<Option<(usize, u32)>, NucleotideError> {
+    let distances = hamming_against_many(query, candidates, len)?;
+    Ok(distances
+        .into_iter()
+        .enumerate()
+        .min_by_key(|&(_, dist)| dist))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{as_2bit, hdist_scalar};
+
+    fn barcodes() -> Vec<u64> {
+        [
+            b"AAAAAAAA",
+            b"ACGTACGT",
+            b"TTTTTTTT",
+            b"ACGTACGA",
+            b"GGGGCCCC",
+            b"ACCTACGT",
+        ]
+        .iter()
+        .map(|seq| as_2bit(*seq).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_hamming_against_many() {
+        let query = as_2bit(b"ACGTACGT").unwrap();
+        let candidates = barcodes();
+        let distances = hamming_against_many(query, &candidates, 8).unwrap();
+        assert_eq!(distances, vec![6, 0, 6, 1, 6, 1]);
+
+        // Matches the pairwise scalar distance
+        for (candidate, dist) in candidates.iter().zip(distances) {
+            assert_eq!(hdist_scalar(query, *candidate, 8), Ok(dist));
+        }
+    }
+
+    #[test]
+    fn test_hamming_against_many_ignores_trailing_bits() {
+        let query = as_2bit(b"ACGT").unwrap();
+        let candidates = [query | (0b11 << 8); 5];
+        assert_eq!(hamming_against_many(query, &candidates, 4), Ok(vec![0; 5]));
+        assert_eq!(
+            hamming_against_many(query, &candidates, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_nearest_2bit() {
+        let candidates = barcodes();
+        let query = as_2bit(b"ACGTACGG").unwrap();
+        assert_eq!(nearest_2bit(query, &candidates, 8), Ok(Some((1, 1))));
+
+        let query = as_2bit(b"GGGGCCCA").unwrap();
+        assert_eq!(nearest_2bit(query, &candidates, 8), Ok(Some((4, 1))));
+
+        assert_eq!(nearest_2bit(query, &[], 8), Ok(None));
+    }
+}
@@ -1,5 +1,8 @@
+mod many;
 mod multi;
 mod scalar;
 
-pub use multi::hdist;
+pub(crate) use many::masked_distance;
+pub use many::{hamming_against_many, nearest_2bit};
+pub use multi::{hamming_blocks, hdist};
 pub use scalar::hdist_scalar;
@@ -159,6 +159,41 @@ pub fn hdist(ebuf1: &[u64], ebuf2: &[u64], n_bases: usize) -> Result<u32, Nucleo
     Ok(total_dist)
 }
 
+/// Calculates the Hamming distance between two aligned block encoded sequences.
+///
+/// This is [`hdist`] for buffers that hold the same number of blocks, such as
+/// two reads of the same length. Unused bits of the final block are ignored.
+///
+/// # Arguments
+///
+/// * `a` - The first encoded sequence buffer
+/// * `b` - The second encoded sequence buffer
+/// * `total_len` - The aligned length of both sequences in bases
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if the buffers differ in length or
+/// are too short to hold `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{encode_alloc, hamming_blocks};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = encode_alloc(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA")?;
+/// let b = encode_alloc(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAT")?;
+/// assert_eq!(hamming_blocks(&a, &b, 40)?, 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn hamming_blocks(a: &[u64], b: &[u64], total_len: usize) -> Result<u64, NucleotideError> {
+    if a.len() != b.len() {
+        return Err(NucleotideError::InvalidLength(b.len()));
+    }
+    hdist(a, b, total_len).map(u64::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,4 +240,36 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_hamming_blocks() {
+        let seq1: Vec<u8> = (0..50)
+            .map(|i| b"ACGTTGCAAG"[(i * 7 + i / 5) % 10])
+            .collect();
+        let mut seq2 = seq1.clone();
+        for position in [0, 13, 31, 32, 49] {
+            seq2[position] = if seq1[position] == b'A' { b'C' } else { b'A' };
+        }
+        let buf1 = encode_alloc(&seq1).unwrap();
+        let buf2 = encode_alloc(&seq2).unwrap();
+        assert_eq!(hamming_blocks(&buf1, &buf2, 50), Ok(5));
+        assert_eq!(hamming_blocks(&buf1, &buf2, 49), Ok(4));
+
+        // Stray bits past the aligned length are ignored
+        let stray = vec![buf2[0], buf2[1] | (u64::MAX << 36)];
+        assert_eq!(hamming_blocks(&buf1, &stray, 50), Ok(5));
+    }
+
+    #[test]
+    fn test_hamming_blocks_errors() {
+        let buf = encode_alloc(&[b'A'; 50]).unwrap();
+        assert_eq!(
+            hamming_blocks(&buf, &buf[..1], 32),
+            Err(NucleotideError::InvalidLength(1))
+        );
+        assert_eq!(
+            hamming_blocks(&buf, &buf, 65),
+            Err(NucleotideError::InvalidLength(65))
+        );
+    }
 }
@@ -1,7 +1,7 @@
-use crate::NucleotideError;
+use crate::utils::{window_mask, LOWER_BITS};
+use crate::{NucleotideError, MAX_BASES};
 
-// Create masks for lower and upper bits of each 2-bit group
-const LOWER_BITS: u64 = 0x5555555555555555;
+// Mask of the upper bit of each 2-bit group
 const UPPER_BITS: u64 = 0xAAAAAAAAAAAAAAAA;
 
 /// Calculate hamming distance between two 2-bit encoded u64 values
@@ -10,7 +10,7 @@ const UPPER_BITS: u64 = 0xAAAAAAAAAAAAAAAA;
 #[inline]
 pub fn hdist_scalar(u: u64, v: u64, len: usize) -> Result<u32, NucleotideError> {
     // Validate length
-    if len > 32 {
+    if len > MAX_BASES {
         return Err(NucleotideError::InvalidLength(len));
     }
 
@@ -19,15 +19,8 @@ pub fn hdist_scalar(u: u64, v: u64, len: usize) -> Result<u32, NucleotideError>
         return Ok(0);
     }
 
-    // Calculate number of valid bits (2 bits per base)
-    let valid_bits = len * 2;
-
     // Create mask for valid bits
-    let mask = if valid_bits == 64 {
-        u64::MAX
-    } else {
-        (1u64 << valid_bits) - 1
-    };
+    let mask = window_mask(len);
 
     // XOR to find differences and mask to valid region
     let diff = (u ^ v) & mask;
@@ -0,0 +1,431 @@
+use std::hash::{BuildHasher, Hasher};
+
+use super::revcomp::{canonical_key_2bit, reverse_complement_blocks};
+use crate::utils::window_mask;
+use crate::{as_2bit, NucleotideError, MAX_BASES};
+
+/// Hashes a 2-bit packed k-mer with a strong bit finalizer.
+///
+/// Packed k-mers make poor hash keys on their own: their low bits only depend
+/// on the first few bases, so they cluster when used with power-of-two bucket
+/// counts. This applies the splitmix64 finalizer so that every output bit
+/// depends on every input bit.
+///
+/// The mix is a bijection on `u64`, so distinct k-mers never collide.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, hash_kmer};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = hash_kmer(as_2bit(b"ACGT")?);
+/// let b = hash_kmer(as_2bit(b"ACGA")?);
+/// assert_ne!(a, b);
+/// assert_eq!(a, hash_kmer(as_2bit(b"ACGT")?));
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn hash_kmer(packed: u64) -> u64 {
+    let x = (packed ^ (packed >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    let x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^ (x >> 31)
+}
+
+/// Hashes the canonical form of a 2-bit packed k-mer.
+///
+/// The k-mer is canonicalized with [`canonical_key_2bit`](crate::canonical_key_2bit)
+/// and then mixed with [`hash_kmer`], so a k-mer and its reverse complement hash
+/// identically. Bits beyond `len` are ignored.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed k-mer
+/// * `len` - The number of bases in the k-mer
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, hash_canonical};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let fwd = hash_canonical(as_2bit(b"AACG")?, 4)?;
+/// let rev = hash_canonical(as_2bit(b"CGTT")?, 4)?;
+/// assert_eq!(fwd, rev);
+/// # Ok(())
+/// # }
+/// ```
+pub fn hash_canonical(packed: u64, len: usize) -> Result<u64, NucleotideError> {
+    let (canonical, _) = canonical_key_2bit(packed, len)?;
+    Ok(hash_kmer(canonical))
+}
+
+/// Packs a nucleotide sequence and hashes it together with its length.
+///
+/// Packing alone maps sequences that differ only by trailing A's (such as `AA`
+/// and `AAA`) to the same value, so the length is mixed in before the
+/// [`hash_kmer`] finalizer. Sequences with equal packed values but different
+/// lengths are guaranteed to have different fingerprints.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::fingerprint;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(fingerprint(b"ACGT")?, fingerprint(b"acgt")?);
+/// assert_ne!(fingerprint(b"ACGT")?, fingerprint(b"ACGTA")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn fingerprint(seq: &[u8]) -> Result<u64, NucleotideError> {
+    let packed = as_2bit(seq)?;
+    Ok(hash_kmer(packed ^ hash_kmer(seq.len() as u64)))
+}
+
+/// Hashes the first `total_len` bases of a block encoded sequence, seeded with its length.
+///
+/// The caller guarantees that `blocks` holds `total_len` bases.
+fn hash_blocks(blocks: &[u64], total_len: usize) -> u64 {
+    let mut remaining = total_len;
+    blocks[..total_len.div_ceil(32)]
+        .iter()
+        .fold(hash_kmer(total_len as u64), |hash, &block| {
+            let block = block & window_mask(remaining);
+            remaining = remaining.saturating_sub(32);
+            hash_kmer(hash ^ block)
+        })
+}
+
+/// Hashes a block encoded sequence identically to its reverse complement.
+///
+/// The sequence and its full reverse complement (from
+/// [`reverse_complement_blocks`](crate::reverse_complement_blocks)) are each
+/// hashed block by block with [`hash_kmer`], seeded with the length, and the
+/// smaller hash is returned. Unused bits of the final block are ignored.
+///
+/// # Arguments
+///
+/// * `blocks` - The encoded sequence buffer
+/// * `total_len` - The length of the sequence in bases
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `blocks` is too short to hold `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{canonical_fingerprint_blocks, encode_alloc};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let fwd = encode_alloc(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACG")?;
+/// let rev = encode_alloc(b"CGTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTT")?;
+/// assert_eq!(
+///     canonical_fingerprint_blocks(&fwd, 36)?,
+///     canonical_fingerprint_blocks(&rev, 36)?
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn canonical_fingerprint_blocks(
+    blocks: &[u64],
+    total_len: usize,
+) -> Result<u64, NucleotideError> {
+    let rc = reverse_complement_blocks(blocks, total_len)?;
+    Ok(hash_blocks(blocks, total_len).min(hash_blocks(&rc, total_len)))
+}
+
+/// Maximum number of bases sampled by [`lsh_bucket`]
+const LSH_SAMPLES: usize = 8;
+
+/// Assigns a 2-bit packed sequence to a locality-sensitive bucket.
+///
+/// The bucket is derived only from the bases at up to 8 evenly spaced
+/// positions, which are folded into a key and mixed with [`hash_kmer`]. This is
+/// bit-sampling LSH for Hamming distance: sequences that agree at the sampled
+/// positions always share a bucket, so a sequence at Hamming distance `d` from
+/// another keeps its bucket unless one of the `d` mismatches lands on a sampled
+/// position. For `len` bases that happens with probability of at most about
+/// `d * 8 / len`. Sequences that differ at a sampled position are spread
+/// uniformly across the buckets.
+///
+/// Bits beyond `len` are ignored.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence
+/// * `buckets` - The number of buckets
+///
+/// # Returns
+///
+/// Returns a bucket index in `0..buckets`.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32 or if
+/// `buckets` is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, lsh_bucket};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // Position 1 is not sampled, so this substitution keeps the bucket
+/// let a = as_2bit(b"ACGTACGTACGTACGTACGTACGTACGTACGT")?;
+/// let b = as_2bit(b"AAGTACGTACGTACGTACGTACGTACGTACGT")?;
+/// assert_eq!(lsh_bucket(a, 32, 1024)?, lsh_bucket(b, 32, 1024)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn lsh_bucket(packed: u64, len: usize, buckets: usize) -> Result<usize, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if buckets == 0 {
+        return Err(NucleotideError::InvalidLength(buckets));
+    }
+
+    let samples = len.min(LSH_SAMPLES);
+    let mut key = 0u64;
+    for i in 0..samples {
+        let position = i * len / samples;
+        key |= ((packed >> (position * 2)) & 0b11) << (i * 2);
+    }
+
+    // Mix in the length so that different window sizes do not share keys
+    let hash = hash_kmer(key ^ ((len as u64) << 32));
+    Ok((hash % buckets as u64) as usize)
+}
+
+/// A [`BuildHasher`] for maps keyed by 2-bit packed k-mers.
+///
+/// Keys are hashed with [`hash_kmer`], which spreads packed k-mers evenly
+/// across buckets.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, KmerHasher};
+/// use std::collections::HashMap;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut counts: HashMap<u64, usize, KmerHasher> = HashMap::default();
+/// *counts.entry(as_2bit(b"ACGT")?).or_default() += 1;
+/// assert_eq!(counts[&as_2bit(b"ACGT")?], 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KmerHasher;
+
+impl BuildHasher for KmerHasher {
+    type Hasher = KmerHash;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        KmerHash::default()
+    }
+}
+
+/// The [`Hasher`] built by [`KmerHasher`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KmerHash {
+    state: u64,
+}
+
+impl Hasher for KmerHash {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        // Generic fallback for non-u64 keys
+        for chunk in bytes.chunks(8) {
+            let mut buf = [0u8; 8];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(buf));
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.state = hash_kmer(self.state ^ i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{encode_alloc, reverse_complement_2bit};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_hash_kmer_deterministic() {
+        let kmer = as_2bit(b"ACGTTGCA").unwrap();
+        assert_eq!(hash_kmer(kmer), hash_kmer(kmer));
+        assert_eq!(KmerHasher.hash_one(kmer), hash_kmer(kmer));
+    }
+
+    #[test]
+    fn test_hash_kmer_distribution() {
+        // Sequential k-mers share their high bits, so bucket by the low bits
+        let n_buckets = 16;
+        let mut buckets = vec![0usize; n_buckets];
+        for kmer in 0..1024u64 {
+            buckets[(hash_kmer(kmer) % n_buckets as u64) as usize] += 1;
+        }
+
+        // Each bucket expects 64 entries
+        for &count in &buckets {
+            assert!((32..=96).contains(&count), "Uneven buckets: {:?}", buckets);
+        }
+    }
+
+    #[test]
+    fn test_kmer_hasher_map() {
+        let mut counts: HashMap<u64, usize, KmerHasher> = HashMap::default();
+        for kmer in [b"ACGT", b"ACGT", b"TTTT", b"AAAA"] {
+            *counts.entry(as_2bit(kmer).unwrap()).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&as_2bit(b"ACGT").unwrap()], 2);
+        assert_eq!(counts[&0], 1);
+    }
+
+    #[test]
+    fn test_hash_canonical() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGG";
+        let kmer = as_2bit(seq).unwrap();
+        let rc = reverse_complement_2bit(kmer, seq.len()).unwrap();
+        assert_eq!(
+            hash_canonical(kmer, seq.len()),
+            hash_canonical(rc, seq.len())
+        );
+        assert_eq!(hash_canonical(kmer, seq.len()), Ok(hash_kmer(kmer.min(rc))));
+    }
+
+    #[test]
+    fn test_hash_canonical_errors() {
+        let kmer = as_2bit(b"ACG").unwrap();
+        assert_eq!(
+            hash_canonical(kmer | (0b11 << 6), 3),
+            hash_canonical(kmer, 3)
+        );
+        assert_eq!(
+            hash_canonical(kmer, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_lsh_bucket_identical() {
+        let seqs: [&[u8]; 4] = [b"A", b"GATTACA", b"ACGTTGCAACGTTGCA", &[b'T'; 32]];
+        for seq in seqs {
+            let packed = as_2bit(seq).unwrap();
+            for buckets in [1, 7, 1024] {
+                let bucket = lsh_bucket(packed, seq.len(), buckets).unwrap();
+                assert!(bucket < buckets);
+                assert_eq!(lsh_bucket(packed, seq.len(), buckets), Ok(bucket));
+
+                // Stray bits past the sequence do not move it
+                let stray = packed | !window_mask(seq.len());
+                assert_eq!(lsh_bucket(stray, seq.len(), buckets), Ok(bucket));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lsh_bucket_unsampled_mismatch() {
+        let a = as_2bit(b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA").unwrap();
+        // Positions 0, 4, 8, ..., 28 are sampled for 32 bases
+        for position in (0..32).filter(|p| p % 4 != 0) {
+            let b = a ^ (0b01 << (position * 2));
+            assert_eq!(lsh_bucket(a, 32, 1 << 20), lsh_bucket(b, 32, 1 << 20));
+        }
+    }
+
+    #[test]
+    fn test_lsh_bucket_errors() {
+        assert_eq!(
+            lsh_bucket(0, 33, 8),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(lsh_bucket(0, 4, 0), Err(NucleotideError::InvalidLength(0)));
+        assert_eq!(lsh_bucket(0, 0, 8), lsh_bucket(u64::MAX, 0, 8));
+    }
+
+    #[test]
+    fn test_fingerprint_length() {
+        assert_eq!(as_2bit(b"AA").unwrap(), as_2bit(b"AAA").unwrap());
+        assert_ne!(fingerprint(b"AA").unwrap(), fingerprint(b"AAA").unwrap());
+        assert_ne!(fingerprint(b"").unwrap(), fingerprint(b"A").unwrap());
+        assert_eq!(fingerprint(b"GATTACA"), fingerprint(b"GATTACA"));
+    }
+
+    #[test]
+    fn test_fingerprint_errors() {
+        assert_eq!(
+            fingerprint(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+        assert_eq!(fingerprint(b"AN"), Err(NucleotideError::InvalidBase(b'N')));
+    }
+
+    #[test]
+    fn test_canonical_fingerprint_blocks_revcomp() {
+        for len in [1, 31, 32, 33, 64, 100] {
+            let seq: Vec<u8> = (0..len)
+                .map(|i| b"ACGTTGCAAG"[(i * 7 + i / 5) % 10])
+                .collect();
+            let blocks = encode_alloc(&seq).unwrap();
+            let rc = reverse_complement_blocks(&blocks, len).unwrap();
+            assert_eq!(
+                canonical_fingerprint_blocks(&blocks, len),
+                canonical_fingerprint_blocks(&rc, len),
+                "Failed for length {}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn test_canonical_fingerprint_blocks_distinct() {
+        let a = encode_alloc(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACCCC").unwrap();
+        let b = encode_alloc(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACCCG").unwrap();
+        assert_ne!(
+            canonical_fingerprint_blocks(&a, 40),
+            canonical_fingerprint_blocks(&b, 40)
+        );
+
+        // The length is part of the fingerprint, and stray bits are ignored
+        assert_ne!(
+            canonical_fingerprint_blocks(&a, 38),
+            canonical_fingerprint_blocks(&a, 39)
+        );
+        let stray = vec![a[0], a[1] | (u64::MAX << 16)];
+        assert_eq!(
+            canonical_fingerprint_blocks(&a, 40),
+            canonical_fingerprint_blocks(&stray, 40)
+        );
+        assert_eq!(
+            canonical_fingerprint_blocks(&a, 65),
+            Err(NucleotideError::InvalidLength(65))
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::utils::window_mask;
+use crate::NucleotideError;
+
+/// Spreads the 16 low 2-bit groups of a u64 so each is followed by an empty group.
+#[inline]
+fn spread_2bit_groups(packed: u64) -> u64 {
+    let x = packed & 0xFFFFFFFF;
+    let x = (x | (x << 16)) & 0x0000FFFF0000FFFF;
+    let x = (x | (x << 8)) & 0x00FF00FF00FF00FF;
+    let x = (x | (x << 4)) & 0x0F0F0F0F0F0F0F0F;
+    (x | (x << 2)) & 0x3333333333333333
+}
+
+/// Compacts every other 2-bit group of a u64 into the low 32 bits.
+///
+/// This is the inverse of [`spread_2bit_groups`].
+#[inline]
+fn compact_2bit_groups(packed: u64) -> u64 {
+    let x = packed & 0x3333333333333333;
+    let x = (x | (x >> 2)) & 0x0F0F0F0F0F0F0F0F;
+    let x = (x | (x >> 4)) & 0x00FF00FF00FF00FF;
+    let x = (x | (x >> 8)) & 0x0000FFFF0000FFFF;
+    (x | (x >> 16)) & 0xFFFFFFFF
+}
+
+/// Interleaves the bases of two 2-bit encoded sequences.
+///
+/// The output alternates bases as `a[0], b[0], a[1], b[1], ...` and holds `2 * len` bases.
+/// Bits beyond `len` bases in either input are ignored.
+///
+/// # Arguments
+///
+/// * `a` - A u64 containing the first 2-bit packed sequence
+/// * `b` - A u64 containing the second 2-bit packed sequence
+/// * `len` - The number of bases in each sequence (<= 16)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `2 * len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, interleave_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = as_2bit(b"AC")?;
+/// let b = as_2bit(b"GT")?;
+/// assert_eq!(interleave_2bit(a, b, 2)?, as_2bit(b"AGCT")?);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn interleave_2bit(a: u64, b: u64, len: usize) -> Result<u64, NucleotideError> {
+    if len > 16 {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let mask = window_mask(len);
+
+    Ok(spread_2bit_groups(a & mask) | (spread_2bit_groups(b & mask) << 2))
+}
+
+/// Splits an interleaved 2-bit encoded sequence back into its two source sequences.
+///
+/// This is the inverse of [`interleave_2bit`].
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the interleaved 2-bit packed sequence of `2 * len` bases
+/// * `len` - The number of bases in each output sequence (<= 16)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `2 * len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, deinterleave_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (a, b) = deinterleave_2bit(as_2bit(b"AGCT")?, 2)?;
+/// assert_eq!(a, as_2bit(b"AC")?);
+/// assert_eq!(b, as_2bit(b"GT")?);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn deinterleave_2bit(packed: u64, len: usize) -> Result<(u64, u64), NucleotideError> {
+    if len > 16 {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let mask = window_mask(len);
+
+    let a = compact_2bit_groups(packed) & mask;
+    let b = compact_2bit_groups(packed >> 2) & mask;
+    Ok((a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_interleave() {
+        let a = as_2bit(b"AC").unwrap();
+        let b = as_2bit(b"GT").unwrap();
+        assert_eq!(interleave_2bit(a, b, 2), Ok(as_2bit(b"AGCT").unwrap()));
+    }
+
+    #[test]
+    fn test_interleave_round_trip() {
+        let a = as_2bit(b"ACTGGAAAATTTTAAG").unwrap();
+        let b = as_2bit(b"TTGCACGTAACCGGTA").unwrap();
+
+        let interleaved = interleave_2bit(a, b, 16).unwrap();
+        assert_eq!(
+            interleaved,
+            as_2bit(b"ATCTTGGCGAACAGATAATATCTCTGAGATGA").unwrap()
+        );
+        assert_eq!(deinterleave_2bit(interleaved, 16), Ok((a, b)));
+    }
+
+    #[test]
+    fn test_interleave_ignores_unused_bits() {
+        let a = as_2bit(b"A").unwrap() | (0b11 << 2);
+        let b = as_2bit(b"C").unwrap() | (0b11 << 2);
+        assert_eq!(interleave_2bit(a, b, 1), Ok(as_2bit(b"AC").unwrap()));
+    }
+
+    #[test]
+    fn test_interleave_invalid_length() {
+        assert_eq!(
+            interleave_2bit(0, 0, 17),
+            Err(NucleotideError::InvalidLength(17))
+        );
+        assert_eq!(
+            deinterleave_2bit(0, 17),
+            Err(NucleotideError::InvalidLength(17))
+        );
+    }
+}
@@ -0,0 +1,778 @@
+use super::revcomp::reverse_complement_2bit;
+use crate::utils::packing::base_to_2bit;
+use crate::utils::window_mask;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Enumerates every k-mer at a Hamming distance of exactly one from `kmer`.
+///
+/// Neighbors are ordered by position, then by base (A, C, G, T), skipping the
+/// original base at each position.
+/// Bits beyond `k` bases in `kmer` are ignored and cleared in the output.
+///
+/// # Arguments
+///
+/// * `kmer` - A u64 containing the 2-bit packed k-mer
+/// * `k` - The number of bases in the k-mer (<= 32)
+///
+/// # Returns
+///
+/// Returns the `3 * k` packed neighbors.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, hamming1_neighbors};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let neighbors = hamming1_neighbors(as_2bit(b"A")?, 1)?;
+/// assert_eq!(neighbors, vec![as_2bit(b"C")?, as_2bit(b"G")?, as_2bit(b"T")?]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn hamming1_neighbors(kmer: u64, k: usize) -> Result<Vec<u64>, NucleotideError> {
+    if k > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(k));
+    }
+
+    let mask = window_mask(k);
+    let kmer = kmer & mask;
+
+    let mut neighbors = Vec::with_capacity(3 * k);
+    for pos in 0..k {
+        let shift = pos * 2;
+        let original = (kmer >> shift) & 0b11;
+        let cleared = kmer & !(0b11 << shift);
+        for code in (0..4u64).filter(|&code| code != original) {
+            neighbors.push(cleared | (code << shift));
+        }
+    }
+    Ok(neighbors)
+}
+
+/// Returns the four k-mers that follow a 2-bit packed k-mer in a de Bruijn graph.
+///
+/// Each successor drops the first base of `kmer` and appends one of A, C, G, T,
+/// returned in that order.
+///
+/// # Arguments
+///
+/// * `kmer` - A u64 containing the 2-bit packed k-mer
+/// * `k` - The number of bases in the k-mer
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, successors_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let successors = successors_2bit(as_2bit(b"ACG")?, 3)?;
+/// assert_eq!(successors[3], as_2bit(b"CGT")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn successors_2bit(kmer: u64, k: usize) -> Result<[u64; 4], NucleotideError> {
+    if k == 0 || k > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(k));
+    }
+    let suffix = (kmer & window_mask(k)) >> 2;
+    let top_shift = (k - 1) * 2;
+    Ok([0, 1, 2, 3].map(|code: u64| suffix | (code << top_shift)))
+}
+
+/// Returns the four k-mers that precede a 2-bit packed k-mer in a de Bruijn graph.
+///
+/// Each predecessor drops the last base of `kmer` and prepends one of A, C, G, T,
+/// returned in that order.
+///
+/// # Arguments
+///
+/// * `kmer` - A u64 containing the 2-bit packed k-mer
+/// * `k` - The number of bases in the k-mer
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, predecessors_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let predecessors = predecessors_2bit(as_2bit(b"ACG")?, 3)?;
+/// assert_eq!(predecessors[3], as_2bit(b"TAC")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn predecessors_2bit(kmer: u64, k: usize) -> Result<[u64; 4], NucleotideError> {
+    if k == 0 || k > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(k));
+    }
+    let prefix = (kmer << 2) & window_mask(k);
+    Ok([0, 1, 2, 3].map(|code: u64| prefix | code))
+}
+
+/// Calls `f` with each k-mer of `seq` in order, packed as a 2-bit u64.
+///
+/// K-mers are rolled forward one base at a time rather than repacked.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+pub(crate) fn for_each_kmer<F: FnMut(u64)>(
+    seq: &[u8],
+    k: usize,
+    mut f: F,
+) -> Result<(), NucleotideError> {
+    if k == 0 || k > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(k));
+    }
+
+    // The incoming base is placed at the highest position of the window
+    let top_shift = (k - 1) * 2;
+    let mut kmer = 0u64;
+    for (i, &base) in seq.iter().enumerate() {
+        kmer = (kmer >> 2) | (base_to_2bit(base)? << top_shift);
+        if i + 1 >= k {
+            f(kmer);
+        }
+    }
+    Ok(())
+}
+
+/// Calls `f` with each k-mer of a block encoded sequence in order, packed as a 2-bit u64.
+///
+/// This is the block counterpart of [`for_each_kmer`], rolling across block
+/// boundaries without unpacking.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32,
+/// or if `blocks` is too short to hold `total_len` bases.
+pub(crate) fn for_each_kmer_blocks<F: FnMut(u64)>(
+    blocks: &[u64],
+    total_len: usize,
+    k: usize,
+    mut f: F,
+) -> Result<(), NucleotideError> {
+    if k == 0 || k > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(k));
+    }
+    if blocks.len() < total_len.div_ceil(32) {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+
+    // The incoming base is placed at the highest position of the window
+    let top_shift = (k - 1) * 2;
+    let mut kmer = 0u64;
+    for i in 0..total_len {
+        let code = (blocks[i / 32] >> ((i % 32) * 2)) & 0b11;
+        kmer = (kmer >> 2) | (code << top_shift);
+        if i + 1 >= k {
+            f(kmer);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the last `len - drop` bases of a 2-bit packed k-mer.
+///
+/// The first `drop` bases are removed and the remaining bases are shifted down
+/// to start at position 0. With `drop = 1` this is the `(k-1)`-mer suffix that
+/// labels a de Bruijn graph edge's target.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed k-mer
+/// * `drop` - The number of leading bases to remove
+/// * `len` - The number of bases in the k-mer
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32 or
+/// `drop` is greater than `len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, suffix_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(suffix_2bit(as_2bit(b"GATTACA")?, 4, 7)?, as_2bit(b"ACA")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn suffix_2bit(packed: u64, drop: usize, len: usize) -> Result<u64, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if drop > len {
+        return Err(NucleotideError::InvalidLength(drop));
+    }
+    let shifted = packed.checked_shr((drop * 2) as u32).unwrap_or(0);
+    Ok(shifted & window_mask(len - drop))
+}
+
+/// Returns the first `len - drop` bases of a 2-bit packed k-mer.
+///
+/// The last `drop` bases are removed. With `drop = 1` this is the `(k-1)`-mer
+/// prefix that labels a de Bruijn graph edge's source.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed k-mer
+/// * `drop` - The number of trailing bases to remove
+/// * `len` - The number of bases in the k-mer
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32 or
+/// `drop` is greater than `len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, prefix_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(prefix_2bit(as_2bit(b"GATTACA")?, 4, 7)?, as_2bit(b"GAT")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn prefix_2bit(packed: u64, drop: usize, len: usize) -> Result<u64, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if drop > len {
+        return Err(NucleotideError::InvalidLength(drop));
+    }
+    Ok(packed & window_mask(len - drop))
+}
+
+/// Extracts the k-mer starting at a base position of a block encoded sequence.
+///
+/// K-mers that straddle a block boundary are stitched together from both blocks.
+///
+/// # Arguments
+///
+/// * `blocks` - The encoded sequence buffer
+/// * `total_len` - The length of the sequence in bases
+/// * `pos` - The position of the first base of the k-mer
+/// * `k` - The k-mer length
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is greater than 32, or if
+/// `blocks` is too short to hold `total_len` bases.
+///
+/// Returns `NucleotideError::InvalidRange` if the k-mer extends past `total_len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, encode_alloc, kmer_at_blocks};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = encode_alloc(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAACGTA")?;
+/// assert_eq!(kmer_at_blocks(&blocks, 34, 30, 4)?, as_2bit(b"CGTA")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn kmer_at_blocks(
+    blocks: &[u64],
+    total_len: usize,
+    pos: usize,
+    k: usize,
+) -> Result<u64, NucleotideError> {
+    if k > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(k));
+    }
+    if blocks.len() < total_len.div_ceil(32) {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+    if pos.checked_add(k).is_none_or(|end| end > total_len) {
+        return Err(NucleotideError::InvalidRange {
+            start: pos,
+            end: pos.saturating_add(k),
+            length: total_len,
+        });
+    }
+    if k == 0 {
+        return Ok(0);
+    }
+
+    let block = pos / 32;
+    let offset = pos % 32;
+    let mut kmer = blocks[block] >> (offset * 2);
+    if offset + k > MAX_BASES {
+        // The remaining bases start the next block
+        kmer |= blocks[block + 1] << ((32 - offset) * 2);
+    }
+    Ok(kmer & window_mask(k))
+}
+
+/// Checks whether a k-mer occurs anywhere in a block encoded sequence.
+///
+/// A window is rolled across the blocks one base at a time, stopping at the
+/// first match. When `canonical` is set, a window also matches if it is the
+/// reverse complement of `kmer`, so either strand is found.
+/// Bits beyond `k` bases in `kmer` are ignored.
+///
+/// # Arguments
+///
+/// * `blocks` - The encoded sequence buffer
+/// * `total_len` - The length of the sequence in bases
+/// * `kmer` - A u64 containing the 2-bit packed k-mer to find
+/// * `k` - The k-mer length
+/// * `canonical` - Whether to also match the reverse complement of `kmer`
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32,
+/// or if `blocks` is too short to hold `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, contains_kmer_blocks, encode_alloc};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = encode_alloc(b"GATTACA")?;
+/// assert!(contains_kmer_blocks(&blocks, 7, as_2bit(b"TTAC")?, 4, false)?);
+/// assert!(!contains_kmer_blocks(&blocks, 7, as_2bit(b"GTAA")?, 4, false)?);
+/// assert!(contains_kmer_blocks(&blocks, 7, as_2bit(b"GTAA")?, 4, true)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn contains_kmer_blocks(
+    blocks: &[u64],
+    total_len: usize,
+    kmer: u64,
+    k: usize,
+    canonical: bool,
+) -> Result<bool, NucleotideError> {
+    if k == 0 || k > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(k));
+    }
+    if blocks.len() < total_len.div_ceil(32) {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+
+    let mask = window_mask(k);
+    let target = kmer & mask;
+    let target_rc = if canonical {
+        reverse_complement_2bit(target, k)?
+    } else {
+        target
+    };
+
+    let top_shift = (k - 1) * 2;
+    let mut window = 0u64;
+    for i in 0..total_len {
+        let code = (blocks[i / 32] >> ((i % 32) * 2)) & 0b11;
+        window = (window >> 2) | (code << top_shift);
+        if i + 1 >= k && (window == target || window == target_rc) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Iterates over the canonical k-mers of `seq` in order, packed as 2-bit u64s.
+///
+/// The canonical k-mer is the smaller of the forward k-mer and its reverse
+/// complement. Both strands are rolled forward one base at a time in step,
+/// so neither is repacked or reverse complemented per position.
+///
+/// The whole sequence is validated before the iterator is returned.
+///
+/// # Arguments
+///
+/// * `seq` - The nucleotide sequence
+/// * `k` - The k-mer length
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, canonical_kmers};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // TTG reverse complements to CAA, which sorts first
+/// let kmers: Vec<u64> = canonical_kmers(b"ACTTG", 3)?.collect();
+/// assert_eq!(kmers, vec![as_2bit(b"ACT")?, as_2bit(b"AAG")?, as_2bit(b"CAA")?]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn canonical_kmers(
+    seq: &[u8],
+    k: usize,
+) -> Result<impl Iterator<Item = u64> + '_, NucleotideError> {
+    if k == 0 || k > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(k));
+    }
+    for &base in seq {
+        base_to_2bit(base)?;
+    }
+
+    let mask = window_mask(k);
+    let top_shift = (k - 1) * 2;
+    let mut fwd = 0u64;
+    let mut rc = 0u64;
+    let kmers = seq.iter().enumerate().filter_map(move |(i, &base)| {
+        // Validated above
+        let code = base_to_2bit(base).unwrap_or_default();
+
+        // The incoming base enters the forward k-mer at the highest position
+        // and its complement enters the reverse complement at the lowest
+        fwd = (fwd >> 2) | (code << top_shift);
+        rc = ((rc << 2) | (code ^ 0b11)) & mask;
+        (i + 1 >= k).then_some(fwd.min(rc))
+    });
+    Ok(kmers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{as_2bit, canonical_key_2bit, encode_alloc, hdist_scalar};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_hamming1_neighbors() {
+        let seq = b"ACTGGAAAATTTTAAG";
+        let kmer = as_2bit(seq).unwrap();
+        let neighbors = hamming1_neighbors(kmer, seq.len()).unwrap();
+
+        assert_eq!(neighbors.len(), 3 * seq.len());
+        assert!(!neighbors.contains(&kmer));
+
+        // All neighbors are distinct and a single substitution away
+        let unique: HashSet<_> = neighbors.iter().collect();
+        assert_eq!(unique.len(), neighbors.len());
+        for &neighbor in &neighbors {
+            assert_eq!(hdist_scalar(kmer, neighbor, seq.len()), Ok(1));
+        }
+    }
+
+    #[test]
+    fn test_hamming1_neighbors_full_length() {
+        let neighbors = hamming1_neighbors(u64::MAX, 32).unwrap();
+        assert_eq!(neighbors.len(), 96);
+        assert!(!neighbors.contains(&u64::MAX));
+    }
+
+    #[test]
+    fn test_hamming1_neighbors_edge_cases() {
+        assert_eq!(hamming1_neighbors(0, 0), Ok(Vec::new()));
+        assert_eq!(
+            hamming1_neighbors(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_for_each_kmer() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCT";
+        for k in [1, 5, 31, 32] {
+            let mut observed = Vec::new();
+            for_each_kmer(seq, k, |kmer| observed.push(kmer)).unwrap();
+
+            let expected: Vec<u64> = seq.windows(k).map(|w| as_2bit(w).unwrap()).collect();
+            assert_eq!(observed, expected);
+        }
+    }
+
+    #[test]
+    fn test_for_each_kmer_errors() {
+        assert_eq!(
+            for_each_kmer(b"ACGT", 0, |_| {}),
+            Err(NucleotideError::InvalidLength(0))
+        );
+        assert_eq!(
+            for_each_kmer(b"ACGT", 33, |_| {}),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            for_each_kmer(b"ACNT", 2, |_| {}),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+
+    #[test]
+    fn test_for_each_kmer_blocks_matches_ascii() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCTAGGCTAACGT"; // 50 bases
+        let blocks = crate::encode_alloc(seq).unwrap();
+        for k in [1, 7, 31, 32] {
+            let mut expected = Vec::new();
+            for_each_kmer(seq, k, |kmer| expected.push(kmer)).unwrap();
+            let mut rolled = Vec::new();
+            for_each_kmer_blocks(&blocks, seq.len(), k, |kmer| rolled.push(kmer)).unwrap();
+            assert_eq!(rolled, expected, "Failed for k = {}", k);
+        }
+        assert_eq!(
+            for_each_kmer_blocks(&blocks, 65, 4, |_| {}),
+            Err(NucleotideError::InvalidLength(65))
+        );
+    }
+
+    #[test]
+    fn test_successors() {
+        let expected: Vec<u64> = [b"CGA", b"CGC", b"CGG", b"CGT"]
+            .iter()
+            .map(|s| as_2bit(*s).unwrap())
+            .collect();
+        let successors = successors_2bit(as_2bit(b"ACG").unwrap(), 3).unwrap();
+        assert_eq!(successors.to_vec(), expected);
+    }
+
+    #[test]
+    fn test_predecessors() {
+        let expected: Vec<u64> = [b"AAC", b"CAC", b"GAC", b"TAC"]
+            .iter()
+            .map(|s| as_2bit(*s).unwrap())
+            .collect();
+        let predecessors = predecessors_2bit(as_2bit(b"ACG").unwrap(), 3).unwrap();
+        assert_eq!(predecessors.to_vec(), expected);
+    }
+
+    #[test]
+    fn test_successors_predecessors_inverse() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAC";
+        let kmer = as_2bit(seq).unwrap();
+        let first = kmer & 0b11;
+        let last = kmer >> 62;
+        for (i, &next) in successors_2bit(kmer, 32).unwrap().iter().enumerate() {
+            assert_eq!(next >> 62, i as u64);
+            assert_eq!(predecessors_2bit(next, 32).unwrap()[first as usize], kmer);
+        }
+        for &prev in &predecessors_2bit(kmer, 32).unwrap() {
+            assert_eq!(successors_2bit(prev, 32).unwrap()[last as usize], kmer);
+        }
+    }
+
+    #[test]
+    fn test_successors_predecessors_errors() {
+        assert_eq!(
+            successors_2bit(0, 0),
+            Err(NucleotideError::InvalidLength(0))
+        );
+        assert_eq!(
+            predecessors_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+
+        // Bits beyond the k-mer are ignored
+        let kmer = as_2bit(b"ACG").unwrap();
+        assert_eq!(
+            successors_2bit(kmer | (0b11 << 6), 3),
+            successors_2bit(kmer, 3)
+        );
+        assert_eq!(
+            predecessors_2bit(as_2bit(b"A").unwrap(), 1),
+            Ok([0, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_canonical_kmers_matches_canonical_key() {
+        let seq = b"ACGTTGCAAGGCTTAACCGGTATATCGCGATTTGACCAGT";
+        for k in [1, 3, 7, 16, 31, 32] {
+            let mut expected = Vec::new();
+            for_each_kmer(seq, k, |kmer| {
+                expected.push(canonical_key_2bit(kmer, k).unwrap().0);
+            })
+            .unwrap();
+            let observed: Vec<u64> = canonical_kmers(seq, k).unwrap().collect();
+            assert_eq!(observed, expected, "k = {k}");
+        }
+    }
+
+    #[test]
+    fn test_canonical_kmers_strand_invariant() {
+        let seq = b"GATTACAGATTACA";
+        let rc = b"TGTAATCTGTAATC";
+        let mut fwd: Vec<u64> = canonical_kmers(seq, 5).unwrap().collect();
+        let rev: Vec<u64> = canonical_kmers(rc, 5).unwrap().collect();
+        fwd.reverse();
+        assert_eq!(fwd, rev);
+    }
+
+    #[test]
+    fn test_canonical_kmers_errors() {
+        assert!(matches!(
+            canonical_kmers(b"ACGT", 0),
+            Err(NucleotideError::InvalidLength(0))
+        ));
+        assert!(matches!(
+            canonical_kmers(b"ACGT", 33),
+            Err(NucleotideError::InvalidLength(33))
+        ));
+        assert!(matches!(
+            canonical_kmers(b"ACGN", 2),
+            Err(NucleotideError::InvalidBase(b'N'))
+        ));
+        assert_eq!(canonical_kmers(b"AC", 3).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_kmer_at_blocks_matches_repack() {
+        let seq: Vec<u8> = (0..100)
+            .map(|i| b"ACGTTGCAAG"[(i * 7 + i / 5) % 10])
+            .collect();
+        let blocks = encode_alloc(&seq).unwrap();
+        for k in [1, 5, 17, 32] {
+            for pos in 0..=seq.len() - k {
+                assert_eq!(
+                    kmer_at_blocks(&blocks, seq.len(), pos, k),
+                    Ok(as_2bit(&seq[pos..pos + k]).unwrap()),
+                    "Failed for k = {k}, pos = {pos}"
+                );
+            }
+        }
+
+        // Spans the boundary between the first and second blocks
+        assert_eq!(
+            kmer_at_blocks(&blocks, seq.len(), 20, 20),
+            Ok(as_2bit(&seq[20..40]).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_kmer_at_blocks_errors() {
+        let blocks = encode_alloc(&[b'G'; 40]).unwrap();
+        assert_eq!(kmer_at_blocks(&blocks, 40, 40, 0), Ok(0));
+        assert_eq!(
+            kmer_at_blocks(&blocks, 40, 0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            kmer_at_blocks(&blocks, 40, 30, 11),
+            Err(NucleotideError::InvalidRange {
+                start: 30,
+                end: 41,
+                length: 40
+            })
+        );
+        assert_eq!(
+            kmer_at_blocks(&blocks[..1], 40, 0, 4),
+            Err(NucleotideError::InvalidLength(40))
+        );
+    }
+
+    #[test]
+    fn test_suffix_prefix() {
+        let packed = as_2bit(b"ACGT").unwrap();
+        assert_eq!(suffix_2bit(packed, 1, 4), Ok(as_2bit(b"CGT").unwrap()));
+        assert_eq!(prefix_2bit(packed, 1, 4), Ok(as_2bit(b"ACG").unwrap()));
+
+        assert_eq!(suffix_2bit(packed, 0, 4), Ok(packed));
+        assert_eq!(prefix_2bit(packed, 0, 4), Ok(packed));
+        assert_eq!(suffix_2bit(packed, 4, 4), Ok(0));
+        assert_eq!(prefix_2bit(packed, 4, 4), Ok(0));
+    }
+
+    #[test]
+    fn test_suffix_prefix_full_length() {
+        let seq = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA";
+        let packed = as_2bit(seq).unwrap();
+        for drop in 0..=32 {
+            assert_eq!(
+                suffix_2bit(packed, drop, 32),
+                Ok(as_2bit(&seq[drop..]).unwrap())
+            );
+            assert_eq!(
+                prefix_2bit(packed, drop, 32),
+                Ok(as_2bit(&seq[..32 - drop]).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn test_suffix_prefix_errors() {
+        assert_eq!(suffix_2bit(0, 5, 4), Err(NucleotideError::InvalidLength(5)));
+        assert_eq!(prefix_2bit(0, 5, 4), Err(NucleotideError::InvalidLength(5)));
+        assert_eq!(
+            suffix_2bit(0, 1, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            prefix_2bit(0, 1, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_contains_kmer_blocks_boundary() {
+        let seq: Vec<u8> = (0..50)
+            .map(|i| b"ACGTTGCAAG"[(i * 7 + i / 5) % 10])
+            .collect();
+        let blocks = encode_alloc(&seq).unwrap();
+
+        // Spans the boundary between the first and second blocks
+        let kmer = as_2bit(&seq[28..36]).unwrap();
+        assert_eq!(contains_kmer_blocks(&blocks, 50, kmer, 8, false), Ok(true));
+
+        // Every k-mer of the sequence is found, and only up to total_len
+        for k in [1, 7, 32] {
+            for pos in 0..=seq.len() - k {
+                let kmer = as_2bit(&seq[pos..pos + k]).unwrap();
+                assert_eq!(contains_kmer_blocks(&blocks, 50, kmer, k, false), Ok(true));
+            }
+        }
+        let tail = as_2bit(&seq[24..50]).unwrap();
+        assert_eq!(contains_kmer_blocks(&blocks, 50, tail, 26, false), Ok(true));
+        assert_eq!(
+            contains_kmer_blocks(&blocks, 49, tail, 26, false),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_contains_kmer_blocks_canonical() {
+        let seq = b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAACCGTAAAAAAAAAAAAAAAA";
+        let blocks = encode_alloc(seq).unwrap();
+
+        // ACGG is the reverse complement of CCGT
+        let kmer = as_2bit(b"ACGG").unwrap();
+        assert_eq!(contains_kmer_blocks(&blocks, 50, kmer, 4, false), Ok(false));
+        assert_eq!(contains_kmer_blocks(&blocks, 50, kmer, 4, true), Ok(true));
+        let absent = as_2bit(b"GGGG").unwrap();
+        assert_eq!(
+            contains_kmer_blocks(&blocks, 50, absent, 4, true),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_contains_kmer_blocks_errors() {
+        let blocks = encode_alloc(b"ACGT").unwrap();
+        assert_eq!(
+            contains_kmer_blocks(&blocks, 4, 0, 0, false),
+            Err(NucleotideError::InvalidLength(0))
+        );
+        assert_eq!(
+            contains_kmer_blocks(&blocks, 4, 0, 33, false),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            contains_kmer_blocks(&blocks, 33, 0, 4, false),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -1,5 +1,78 @@
+mod access;
+mod barcode;
+mod compare;
+mod concat;
+mod consensus;
+mod count;
+mod diff;
+mod edit;
+mod endian;
+mod gc;
 mod hamming;
+mod hash;
+mod interleave;
+mod kmer;
+mod normalize;
+mod one_hot;
+mod reader;
+mod repeat;
+mod revcomp;
+mod rotate;
+mod score;
+mod size;
+mod sketch;
 mod split;
+mod trim;
+mod validate;
 
-pub use hamming::{hdist, hdist_scalar};
-pub use split::split_packed;
+pub use access::base_at_2bit;
+pub use barcode::BarcodeIndex;
+pub use compare::{blocks_eq, cmp_2bit, eq_masked_2bit, longest_common_substring_2bit};
+pub use concat::{concat_2bit, fuse_kmers, split_fused};
+pub use consensus::{consensus_base, pack_column};
+pub use count::{
+    count_base_blocks, position_frequency_matrix, positional_entropy, present_bases_2bit,
+    rank_base_2bit,
+};
+pub use diff::{diff_bases_2bit, ts_tv_blocks};
+pub use edit::levenshtein_banded_2bit;
+pub use endian::{from_bits_be, to_bits_be};
+pub use gc::{
+    gc_count_2bit, gc_count_blocks, gc_fraction_2bit, gc_fraction_2bit_strict, rolling_gc,
+    sliding_gc,
+};
+pub use hamming::{hamming_against_many, hamming_blocks, hdist, hdist_scalar, nearest_2bit};
+pub use hash::{
+    canonical_fingerprint_blocks, fingerprint, hash_canonical, hash_kmer, lsh_bucket, KmerHash,
+    KmerHasher,
+};
+pub use interleave::{deinterleave_2bit, interleave_2bit};
+pub use kmer::{
+    canonical_kmers, contains_kmer_blocks, hamming1_neighbors, kmer_at_blocks, predecessors_2bit,
+    prefix_2bit, successors_2bit, suffix_2bit,
+};
+pub use normalize::normalize_nucleotides;
+pub use one_hot::one_hot_2bit;
+pub use reader::PackedReader;
+pub use repeat::{
+    from_rle_2bit, is_homopolymer_2bit, poly_tail_len_2bit, repeat_period_2bit,
+    run_length_histogram_2bit, to_rle_2bit,
+};
+pub use revcomp::{
+    as_2bit_both_strands, canonical_bases, canonical_key_2bit, find_palindromes,
+    is_palindrome_2bit, is_reverse_complement_2bit, revcomp_bases, reverse_complement_2bit,
+    reverse_complement_2bit_alphabet, reverse_complement_blocks,
+};
+pub use rotate::{min_rotation_2bit, rotate_left_2bit, rotate_right_2bit};
+pub use score::ungapped_score_2bit;
+pub use size::{compression_ratio, packed_size_bytes};
+pub use sketch::{
+    jaccard_blocks, kmer_complexity, kmer_cosine, minhash_similarity, minhash_sketch,
+};
+pub use split::{split_packed, tiles_2bit};
+pub use trim::trim_by_mask_2bit;
+pub use validate::{
+    all_invalid_positions, is_well_formed_2bit, validate_batch, validate_reader, BatchReport,
+};
+
+pub(crate) use access::BASES;
@@ -0,0 +1,130 @@
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+use std::arch::x86_64::*;
+
+use crate::utils::packing::base_to_2bit;
+use crate::NucleotideError;
+
+/// Appends the uppercase form of `seq` to `out`, failing on the first non-nucleotide.
+#[inline]
+fn normalize_scalar(seq: &[u8], out: &mut Vec<u8>) -> Result<(), NucleotideError> {
+    for &base in seq {
+        base_to_2bit(base)?;
+        out.push(base.to_ascii_uppercase());
+    }
+    Ok(())
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+#[inline]
+unsafe fn normalize_avx2(seq: &[u8], out: &mut Vec<u8>) -> Result<(), NucleotideError> {
+    // Clearing bit 5 maps lowercase acgt onto uppercase ACGT and no other byte onto them
+    let case_mask = _mm256_set1_epi8(!0x20u8 as i8);
+    let a = _mm256_set1_epi8(b'A' as i8);
+    let c = _mm256_set1_epi8(b'C' as i8);
+    let g = _mm256_set1_epi8(b'G' as i8);
+    let t = _mm256_set1_epi8(b'T' as i8);
+
+    let mut chunks = seq.chunks_exact(32);
+    for chunk in chunks.by_ref() {
+        let bytes = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+        let upper = _mm256_and_si256(bytes, case_mask);
+
+        let valid = _mm256_or_si256(
+            _mm256_or_si256(_mm256_cmpeq_epi8(upper, a), _mm256_cmpeq_epi8(upper, c)),
+            _mm256_or_si256(_mm256_cmpeq_epi8(upper, g), _mm256_cmpeq_epi8(upper, t)),
+        );
+
+        // Let the scalar path pinpoint the offending byte
+        if _mm256_movemask_epi8(valid) != -1 {
+            return normalize_scalar(chunk, out);
+        }
+
+        let mut normalized = [0u8; 32];
+        _mm256_storeu_si256(normalized.as_mut_ptr() as *mut __m256i, upper);
+        out.extend_from_slice(&normalized);
+    }
+
+    normalize_scalar(chunks.remainder(), out)
+}
+
+/// Validates a nucleotide sequence and returns an uppercase copy of it.
+///
+/// Lowercase `acgt` are mapped to `ACGT`. Blocks of 32 bases are validated and
+/// uppercased with SIMD when available.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// other than A,C,G,T (case insensitive).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::normalize_nucleotides;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(normalize_nucleotides(b"gaTTaca")?, b"GATTACA");
+/// assert!(normalize_nucleotides(b"gaNNaca").is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn normalize_nucleotides(seq: &[u8]) -> Result<Vec<u8>, NucleotideError> {
+    let mut out = Vec::with_capacity(seq.len());
+
+    #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+    if is_x86_feature_detected!("avx2") {
+        unsafe { normalize_avx2(seq, &mut out)? };
+        return Ok(out);
+    }
+
+    normalize_scalar(seq, &mut out)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_nucleotides() {
+        assert_eq!(normalize_nucleotides(b"acGt"), Ok(b"ACGT".to_vec()));
+        assert_eq!(normalize_nucleotides(b""), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_normalize_nucleotides_long() {
+        // Exercise full SIMD blocks and the scalar remainder
+        let seq: Vec<u8> = (0..100)
+            .map(|i| b"acgtACGTtgca"[(i * 5 + i / 3) % 12])
+            .collect();
+        let expected = seq.to_ascii_uppercase();
+        assert_eq!(normalize_nucleotides(&seq), Ok(expected.clone()));
+
+        let mut out = Vec::new();
+        normalize_scalar(&seq, &mut out).unwrap();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_normalize_nucleotides_invalid() {
+        for position in [0, 31, 32, 70, 99] {
+            let mut seq = vec![b'a'; 100];
+            seq[position] = b'n';
+            assert_eq!(
+                normalize_nucleotides(&seq),
+                Err(NucleotideError::InvalidBase(b'n'))
+            );
+
+            // Non-ASCII bytes are reported as such
+            seq[position] = b'A' ^ 0x80;
+            assert_eq!(
+                normalize_nucleotides(&seq),
+                Err(NucleotideError::NonAscii(b'A' ^ 0x80))
+            );
+        }
+    }
+}
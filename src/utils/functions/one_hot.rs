@@ -0,0 +1,74 @@
+use crate::{NucleotideError, MAX_BASES};
+
+/// Expands a 2-bit packed sequence into a one-hot encoding.
+///
+/// Each base becomes a 4-element row with `1.0` in its column and `0.0`
+/// elsewhere, with columns in `A`, `C`, `G`, `T` order.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, one_hot_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"AT")?;
+/// let one_hot = one_hot_2bit(packed, 2)?;
+/// assert_eq!(one_hot, vec![[1.0, 0.0, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0]]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn one_hot_2bit(packed: u64, len: usize) -> Result<Vec<[f32; 4]>, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    Ok((0..len)
+        .map(|i| {
+            let mut row = [0.0; 4];
+            row[((packed >> (i * 2)) & 0b11) as usize] = 1.0;
+            row
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_one_hot() {
+        let packed = as_2bit(b"AC").unwrap();
+        assert_eq!(
+            one_hot_2bit(packed, 2),
+            Ok(vec![[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn test_one_hot_columns() {
+        let seq = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA";
+        let one_hot = one_hot_2bit(as_2bit(seq).unwrap(), seq.len()).unwrap();
+        assert_eq!(one_hot.len(), seq.len());
+        for (row, &base) in one_hot.iter().zip(seq.iter()) {
+            let col = b"ACGT".iter().position(|&b| b == base).unwrap();
+            assert_eq!(row.iter().sum::<f32>(), 1.0);
+            assert_eq!(row[col], 1.0);
+        }
+    }
+
+    #[test]
+    fn test_one_hot_edge_cases() {
+        assert_eq!(one_hot_2bit(u64::MAX, 0), Ok(vec![]));
+        assert_eq!(one_hot_2bit(0, 33), Err(NucleotideError::InvalidLength(33)));
+    }
+}
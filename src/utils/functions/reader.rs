@@ -0,0 +1,124 @@
+use super::access::BASES;
+use crate::NucleotideError;
+
+/// A cursor over a block encoded sequence that unpacks a few bases at a time.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{encode_alloc, PackedReader};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = encode_alloc(b"ACGTACGTAC")?;
+/// let mut reader = PackedReader::new(&blocks, 10)?;
+///
+/// let mut buffer = [0u8; 4];
+/// assert_eq!(reader.read_bases(4, &mut buffer)?, 4);
+/// assert_eq!(&buffer, b"ACGT");
+/// assert_eq!(reader.remaining(), 6);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PackedReader<'a> {
+    blocks: &'a [u64],
+    total_len: usize,
+    position: usize,
+}
+
+impl<'a> PackedReader<'a> {
+    /// Creates a reader over the first `total_len` bases of `blocks`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NucleotideError::InvalidLength` if `blocks` is too short to hold `total_len` bases.
+    pub fn new(blocks: &'a [u64], total_len: usize) -> Result<Self, NucleotideError> {
+        if blocks.len() < total_len.div_ceil(32) {
+            return Err(NucleotideError::InvalidLength(total_len));
+        }
+        Ok(Self {
+            blocks,
+            total_len,
+            position: 0,
+        })
+    }
+
+    /// Returns the index of the next base to be read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bases left to read.
+    pub fn remaining(&self) -> usize {
+        self.total_len - self.position
+    }
+
+    /// Unpacks the next `n` bases (or fewer at the end of the sequence) into `out`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of bases written to the start of `out`, which is 0 once
+    /// the whole sequence has been read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NucleotideError::InvalidLength` if `out` is shorter than `n`.
+    pub fn read_bases(&mut self, n: usize, out: &mut [u8]) -> Result<usize, NucleotideError> {
+        if out.len() < n {
+            return Err(NucleotideError::InvalidLength(n));
+        }
+
+        let count = n.min(self.remaining());
+        for (offset, slot) in out[..count].iter_mut().enumerate() {
+            let index = self.position + offset;
+            let bits = (self.blocks[index / 32] >> ((index % 32) * 2)) & 0b11;
+            *slot = BASES[bits as usize];
+        }
+        self.position += count;
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode_alloc;
+
+    #[test]
+    fn test_read_in_chunks() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCTAGGCTAACGT"; // 50 bases
+        let blocks = encode_alloc(seq).unwrap();
+        let mut reader = PackedReader::new(&blocks, seq.len()).unwrap();
+
+        let mut buffer = [0u8; 7];
+        let mut reassembled = Vec::new();
+        let mut sizes = Vec::new();
+        loop {
+            let count = reader.read_bases(7, &mut buffer).unwrap();
+            if count == 0 {
+                break;
+            }
+            sizes.push(count);
+            reassembled.extend_from_slice(&buffer[..count]);
+        }
+
+        assert_eq!(reassembled, seq);
+        assert_eq!(sizes, vec![7, 7, 7, 7, 7, 7, 7, 1]);
+        assert_eq!(reader.remaining(), 0);
+        assert_eq!(reader.position(), 50);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let blocks = encode_alloc(b"ACGT").unwrap();
+        assert!(PackedReader::new(&blocks, 33).is_err());
+
+        let mut reader = PackedReader::new(&blocks, 4).unwrap();
+        let mut buffer = [0u8; 2];
+        assert_eq!(
+            reader.read_bases(3, &mut buffer),
+            Err(NucleotideError::InvalidLength(3))
+        );
+        assert_eq!(reader.position(), 0);
+    }
+}
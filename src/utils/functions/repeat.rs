@@ -0,0 +1,404 @@
+use super::access::base_at_2bit;
+use crate::utils::packing::base_to_2bit;
+use crate::utils::{window_mask, LOWER_BITS};
+use crate::{NucleotideError, MAX_BASES};
+use std::collections::HashMap;
+
+/// Finds the smallest period of a 2-bit packed sequence.
+///
+/// The period is the smallest `p` such that every base equals the base `p`
+/// positions before it, i.e. the sequence is its first `p` bases repeated (the
+/// final repeat may be partial). Aperiodic sequences have a period of `len`, and
+/// the empty sequence has a period of 0.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, repeat_period_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(repeat_period_2bit(as_2bit(b"CAGCAGCAG")?, 9)?, 3);
+/// assert_eq!(repeat_period_2bit(as_2bit(b"ACGT")?, 4)?, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn repeat_period_2bit(packed: u64, len: usize) -> Result<usize, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    for period in 1..len {
+        let mut periodic = true;
+        for i in period..len {
+            if base_at_2bit(packed, len, i)? != base_at_2bit(packed, len, i % period)? {
+                periodic = false;
+                break;
+            }
+        }
+        if periodic {
+            return Ok(period);
+        }
+    }
+    Ok(len)
+}
+
+/// Checks whether every base of a 2-bit packed sequence is the same.
+///
+/// The first base's code is repeated across the window and compared against the
+/// sequence in one step. Sequences of zero or one base are homopolymers.
+/// Bits beyond `len` are ignored.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, is_homopolymer_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert!(is_homopolymer_2bit(as_2bit(b"GGGGG")?, 5)?);
+/// assert!(!is_homopolymer_2bit(as_2bit(b"GGGGC")?, 5)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_homopolymer_2bit(packed: u64, len: usize) -> Result<bool, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let mask = window_mask(len);
+    let repeated = (packed & 0b11) * LOWER_BITS;
+    Ok((packed ^ repeated) & mask == 0)
+}
+
+/// Counts the consecutive copies of a base at the 3' end of a 2-bit packed sequence.
+///
+/// The run is anchored at the last position (`len - 1`) and extends toward the
+/// start until a different base is found, as for a poly-A tail.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+/// * `base` - The nucleotide of the tail (A,C,G,T, case insensitive)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if `base` is not a nucleotide.
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, poly_tail_len_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"GATTACAAAA")?;
+/// assert_eq!(poly_tail_len_2bit(packed, 10, b'A')?, 4);
+/// assert_eq!(poly_tail_len_2bit(packed, 10, b'T')?, 0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn poly_tail_len_2bit(packed: u64, len: usize, base: u8) -> Result<usize, NucleotideError> {
+    let code = base_to_2bit(base)?;
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    // Collapse each mismatching 2-bit group onto its lower bit
+    let diff = packed ^ (code * LOWER_BITS);
+    let mismatches = (diff | (diff >> 1)) & LOWER_BITS & window_mask(len);
+    if mismatches == 0 {
+        return Ok(len);
+    }
+
+    // The tail ends at the highest mismatching position
+    let last_mismatch = (63 - mismatches.leading_zeros() as usize) / 2;
+    Ok(len - 1 - last_mismatch)
+}
+
+/// Run-length encodes a 2-bit packed sequence.
+///
+/// Each run of identical bases is reported as its 2-bit code (`A=0`, `C=1`,
+/// `G=2`, `T=3`) and length, in sequence order.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, to_rle_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let runs = to_rle_2bit(as_2bit(b"AAACCGT")?, 7)?;
+/// assert_eq!(runs, vec![(0, 3), (1, 2), (2, 1), (3, 1)]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_rle_2bit(packed: u64, len: usize) -> Result<Vec<(u8, u32)>, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let mut runs: Vec<(u8, u32)> = Vec::new();
+    for i in 0..len {
+        let code = ((packed >> (i * 2)) & 0b11) as u8;
+        match runs.last_mut() {
+            Some((last, count)) if *last == code => *count += 1,
+            _ => runs.push((code, 1)),
+        }
+    }
+    Ok(runs)
+}
+
+/// Reconstructs a 2-bit packed sequence from its run-length encoding.
+///
+/// This reverses [`to_rle_2bit`]. Runs of length zero are skipped.
+///
+/// # Arguments
+///
+/// * `runs` - The `(base_code, run_length)` pairs in sequence order
+///
+/// # Returns
+///
+/// Returns the packed value and its length in bases.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if a base code is greater than 3.
+///
+/// Returns `NucleotideError::SequenceTooLong` if the runs total more than 32 bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, from_rle_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (packed, len) = from_rle_2bit(&[(3, 2), (0, 1)])?;
+/// assert_eq!((packed, len), (as_2bit(b"TTA")?, 3));
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_rle_2bit(runs: &[(u8, u32)]) -> Result<(u64, usize), NucleotideError> {
+    let total: u64 = runs.iter().map(|&(_, count)| count as u64).sum();
+    if total > MAX_BASES as u64 {
+        return Err(NucleotideError::SequenceTooLong(total as usize));
+    }
+
+    let mut packed = 0u64;
+    let mut len = 0;
+    for &(code, count) in runs {
+        if code > 0b11 {
+            return Err(NucleotideError::InvalidBase(code));
+        }
+        let count = count as usize;
+        if count == 0 {
+            continue;
+        }
+        // Repeat the code across the run
+        let run = (code as u64 * LOWER_BITS) & window_mask(count);
+        packed |= run << (len * 2);
+        len += count;
+    }
+    Ok((packed, len))
+}
+
+/// Counts the homopolymer runs of each length in a 2-bit packed sequence.
+///
+/// Runs are the maximal stretches of identical bases found by [`to_rle_2bit`].
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Returns
+///
+/// Returns a map from run length to the number of runs of that length.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, run_length_histogram_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let histogram = run_length_histogram_2bit(as_2bit(b"AACCT")?, 5)?;
+/// assert_eq!(histogram[&2], 2);
+/// assert_eq!(histogram[&1], 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn run_length_histogram_2bit(
+    packed: u64,
+    len: usize,
+) -> Result<HashMap<usize, u32>, NucleotideError> {
+    let mut histogram = HashMap::new();
+    for (_, run) in to_rle_2bit(packed, len)? {
+        *histogram.entry(run as usize).or_insert(0) += 1;
+    }
+    Ok(histogram)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_repeat_period() {
+        let cases: [(&[u8], usize); 7] = [
+            (b"ATAT", 2),
+            (b"ACGT", 4),
+            (b"AAAA", 1),
+            (b"ACGACGA", 3),
+            (b"ACGACGT", 7),
+            (b"A", 1),
+            (b"", 0),
+        ];
+        for (seq, period) in cases {
+            let packed = as_2bit(seq).unwrap();
+            assert_eq!(repeat_period_2bit(packed, seq.len()), Ok(period));
+        }
+    }
+
+    #[test]
+    fn test_repeat_period_full_width() {
+        let packed = as_2bit(&b"TTAGGG".repeat(6)[..32]).unwrap();
+        assert_eq!(repeat_period_2bit(packed, 32), Ok(6));
+        assert_eq!(
+            repeat_period_2bit(packed, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_rle_round_trip() {
+        let packed = as_2bit(b"AAACCGT").unwrap();
+        let runs = to_rle_2bit(packed, 7).unwrap();
+        assert_eq!(runs, vec![(0, 3), (1, 2), (2, 1), (3, 1)]);
+        assert_eq!(from_rle_2bit(&runs), Ok((packed, 7)));
+    }
+
+    #[test]
+    fn test_rle_full_width() {
+        let packed = as_2bit(&[b'T'; 32]).unwrap();
+        assert_eq!(to_rle_2bit(packed, 32), Ok(vec![(3, 32)]));
+        assert_eq!(from_rle_2bit(&[(3, 32)]), Ok((packed, 32)));
+        assert_eq!(to_rle_2bit(packed, 0), Ok(vec![]));
+        assert_eq!(from_rle_2bit(&[]), Ok((0, 0)));
+        assert_eq!(from_rle_2bit(&[(1, 0), (2, 1)]), Ok((0b10, 1)));
+    }
+
+    #[test]
+    fn test_rle_errors() {
+        assert_eq!(to_rle_2bit(0, 33), Err(NucleotideError::InvalidLength(33)));
+        assert_eq!(
+            from_rle_2bit(&[(0, 30), (1, 3)]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+        assert_eq!(
+            from_rle_2bit(&[(4, 1)]),
+            Err(NucleotideError::InvalidBase(4))
+        );
+    }
+
+    #[test]
+    fn test_is_homopolymer_2bit() {
+        assert_eq!(is_homopolymer_2bit(as_2bit(b"AAAA").unwrap(), 4), Ok(true));
+        assert_eq!(is_homopolymer_2bit(as_2bit(b"AAAT").unwrap(), 4), Ok(false));
+        assert_eq!(is_homopolymer_2bit(as_2bit(b"TAAA").unwrap(), 4), Ok(false));
+        assert_eq!(is_homopolymer_2bit(u64::MAX, 32), Ok(true));
+    }
+
+    #[test]
+    fn test_is_homopolymer_2bit_edge_cases() {
+        assert_eq!(is_homopolymer_2bit(0, 0), Ok(true));
+        assert_eq!(is_homopolymer_2bit(0b10, 1), Ok(true));
+        // Bits past the sequence are ignored
+        let packed = as_2bit(b"CCC").unwrap() | (0b11 << 6);
+        assert_eq!(is_homopolymer_2bit(packed, 3), Ok(true));
+        assert_eq!(is_homopolymer_2bit(packed, 4), Ok(false));
+        assert_eq!(
+            is_homopolymer_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_poly_tail_len_2bit() {
+        let packed = as_2bit(b"CGTAAA").unwrap();
+        assert_eq!(poly_tail_len_2bit(packed, 6, b'A'), Ok(3));
+        assert_eq!(poly_tail_len_2bit(packed, 6, b'a'), Ok(3));
+        assert_eq!(poly_tail_len_2bit(packed, 6, b'T'), Ok(0));
+        // A shorter window moves the 3' end
+        assert_eq!(poly_tail_len_2bit(packed, 4, b'A'), Ok(1));
+        assert_eq!(poly_tail_len_2bit(packed, 3, b'T'), Ok(1));
+    }
+
+    #[test]
+    fn test_poly_tail_len_2bit_edge_cases() {
+        assert_eq!(poly_tail_len_2bit(u64::MAX, 32, b'T'), Ok(32));
+        assert_eq!(poly_tail_len_2bit(0, 0, b'A'), Ok(0));
+        let packed = as_2bit(b"TAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA").unwrap();
+        assert_eq!(poly_tail_len_2bit(packed, 32, b'A'), Ok(31));
+        assert_eq!(
+            poly_tail_len_2bit(0, 4, b'N'),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        assert_eq!(
+            poly_tail_len_2bit(0, 33, b'A'),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_run_length_histogram_2bit() {
+        let histogram = run_length_histogram_2bit(as_2bit(b"AAACCG").unwrap(), 6).unwrap();
+        assert_eq!(histogram, HashMap::from([(3, 1), (2, 1), (1, 1)]));
+
+        let histogram = run_length_histogram_2bit(as_2bit(b"ACGTACGT").unwrap(), 8).unwrap();
+        assert_eq!(histogram, HashMap::from([(1, 8)]));
+    }
+
+    #[test]
+    fn test_run_length_histogram_2bit_edge_cases() {
+        assert_eq!(run_length_histogram_2bit(0, 0), Ok(HashMap::new()));
+        assert_eq!(
+            run_length_histogram_2bit(u64::MAX, 32),
+            Ok(HashMap::from([(32, 1)]))
+        );
+        assert_eq!(
+            run_length_histogram_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,730 @@
+use super::access::BASES;
+use crate::utils::packing::base_to_2bit;
+use crate::utils::window_mask;
+use crate::{as_2bit, from_2bit};
+use crate::{NucleotideError, MAX_BASES};
+
+/// Reverses the order of the 2-bit groups within a u64.
+#[inline]
+fn reverse_2bit_groups(packed: u64) -> u64 {
+    // Swap adjacent 2-bit groups, then nibbles, then bytes
+    let x = ((packed >> 2) & 0x3333333333333333) | ((packed & 0x3333333333333333) << 2);
+    let x = ((x >> 4) & 0x0F0F0F0F0F0F0F0F) | ((x & 0x0F0F0F0F0F0F0F0F) << 4);
+    x.swap_bytes()
+}
+
+/// Calculates the reverse complement of a 2-bit encoded u64 value.
+///
+/// Because of the encoding (A=00, C=01, G=10, T=11) the complement of a base
+/// is its bitwise negation, so this only requires a negation and a reversal
+/// of the base order.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence (<= 32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, reverse_complement_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"AACGT")?;
+/// assert_eq!(reverse_complement_2bit(packed, 5)?, as_2bit(b"ACGTT")?);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn reverse_complement_2bit(packed: u64, len: usize) -> Result<u64, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if len == 0 {
+        return Ok(0);
+    }
+
+    // Reversing moves the first base to the top of the u64, so shift the
+    // window back down to the low bits (discarding the unused bits)
+    Ok(reverse_2bit_groups(!packed) >> (64 - len * 2))
+}
+
+/// Calculates the reverse complement of a 2-bit encoded u64 value under a custom complement.
+///
+/// [`reverse_complement_2bit`] relies on the standard encoding, where complementing
+/// is a bitwise negation. Sequences packed with
+/// [`as_2bit_with_alphabet`](crate::as_2bit_with_alphabet) need the complement
+/// taken within their own alphabet instead, which `complement_map` describes.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence (<= 32)
+/// * `complement_map` - The complementary code of each code, `complement_map[code]`
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if `complement_map` contains a code greater than 3.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit_with_alphabet, reverse_complement_2bit_alphabet};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // A=0, C=1, T=2, G=3, so A<->T is 0<->2 and C<->G is 1<->3
+/// let packed = as_2bit_with_alphabet(b"AACGT", *b"ACTG")?;
+/// let rc = reverse_complement_2bit_alphabet(packed, 5, [2, 3, 0, 1])?;
+/// assert_eq!(rc, as_2bit_with_alphabet(b"ACGTT", *b"ACTG")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn reverse_complement_2bit_alphabet(
+    packed: u64,
+    len: usize,
+    complement_map: [u8; 4],
+) -> Result<u64, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if let Some(&code) = complement_map.iter().find(|&&code| code > 3) {
+        return Err(NucleotideError::InvalidBase(code));
+    }
+
+    Ok((0..len).fold(0, |rc, i| {
+        let code = complement_map[((packed >> (i * 2)) & 0b11) as usize] as u64;
+        rc | (code << ((len - 1 - i) * 2))
+    }))
+}
+
+/// Packs a nucleotide sequence together with its reverse complement.
+///
+/// The sequence is packed once and the reverse complement is derived from the
+/// packed value with [`reverse_complement_2bit`].
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns the forward packed value, the reverse complement packed value, and the length.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_both_strands};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (fwd, rc, len) = as_2bit_both_strands(b"AACG")?;
+/// assert_eq!(fwd, as_2bit(b"AACG")?);
+/// assert_eq!(rc, as_2bit(b"CGTT")?);
+/// assert_eq!(len, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_both_strands(seq: &[u8]) -> Result<(u64, u64, usize), NucleotideError> {
+    let fwd = as_2bit(seq)?;
+    let rc = reverse_complement_2bit(fwd, seq.len())?;
+    Ok((fwd, rc, seq.len()))
+}
+
+/// Checks whether `b` is the reverse complement of `a` over `len` bases.
+///
+/// Bits beyond `len` bases are ignored in both values.
+///
+/// # Arguments
+///
+/// * `a` - A u64 containing the first 2-bit packed sequence
+/// * `b` - A u64 containing the second 2-bit packed sequence
+/// * `len` - The number of bases in each sequence (<= 32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, is_reverse_complement_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = as_2bit(b"AACG")?;
+/// let b = as_2bit(b"CGTT")?;
+/// assert!(is_reverse_complement_2bit(a, b, 4)?);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn is_reverse_complement_2bit(a: u64, b: u64, len: usize) -> Result<bool, NucleotideError> {
+    let rc = reverse_complement_2bit(a, len)?;
+
+    let mask = window_mask(len);
+
+    Ok(rc == b & mask)
+}
+
+/// Checks whether a 2-bit encoded u64 value is a reverse-complement palindrome.
+///
+/// A palindrome reads the same on both strands, as most restriction sites do.
+/// Bits beyond `len` bases are ignored.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence (<= 32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, is_palindrome_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert!(is_palindrome_2bit(as_2bit(b"GAATTC")?, 6)?);
+/// assert!(!is_palindrome_2bit(as_2bit(b"GAATTA")?, 6)?);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn is_palindrome_2bit(packed: u64, len: usize) -> Result<bool, NucleotideError> {
+    is_reverse_complement_2bit(packed, packed, len)
+}
+
+/// Finds every reverse-complement palindrome of a given length in a sequence.
+///
+/// Windows are packed on a rolling basis and each one is checked with
+/// [`is_palindrome_2bit`]. Only even lengths are accepted, since an odd-length
+/// window would need its middle base to be its own complement.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `len` - The length of the palindromes to find (even, <= 32)
+///
+/// # Returns
+///
+/// Returns the start positions of all palindromic windows, in increasing order.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is zero, odd, or greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::find_palindromes;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(find_palindromes(b"TTGAATTCAA", 6)?, vec![2]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn find_palindromes(seq: &[u8], len: usize) -> Result<Vec<usize>, NucleotideError> {
+    if len == 0 || !len.is_multiple_of(2) || len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let top_shift = (len - 1) * 2;
+    let mut window = 0u64;
+    let mut positions = Vec::new();
+    for (i, &base) in seq.iter().enumerate() {
+        window = (window >> 2) | (base_to_2bit(base)? << top_shift);
+        if i + 1 >= len && is_palindrome_2bit(window, len)? {
+            positions.push(i + 1 - len);
+        }
+    }
+    Ok(positions)
+}
+
+/// Calculates the canonical form of a 2-bit encoded u64 value along with its strand.
+///
+/// The canonical form is the smaller of the sequence and its reverse complement.
+/// The returned flag is `true` when the reverse complement was chosen, which lets
+/// callers recover the original orientation.
+/// Palindromic sequences are reported on the forward strand.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the sequence (<= 32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, canonical_key_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (key, is_rc) = canonical_key_2bit(as_2bit(b"TTGC")?, 4)?;
+/// assert_eq!(key, as_2bit(b"GCAA")?);
+/// assert!(is_rc);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn canonical_key_2bit(packed: u64, len: usize) -> Result<(u64, bool), NucleotideError> {
+    let rc = reverse_complement_2bit(packed, len)?;
+
+    let mask = window_mask(len);
+    let fwd = packed & mask;
+
+    if rc < fwd {
+        Ok((rc, true))
+    } else {
+        Ok((fwd, false))
+    }
+}
+
+/// Returns the canonical strand of a nucleotide sequence as ASCII bases.
+///
+/// This packs the sequence, picks its canonical form as [`canonical_key_2bit`]
+/// does, and unpacks the result. Bases are returned in uppercase.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns the canonical bases and `true` if they are the reverse complement of `seq`.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::canonical_bases;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(canonical_bases(b"ttgc")?, (b"GCAA".to_vec(), true));
+/// assert_eq!(canonical_bases(b"GCAA")?, (b"GCAA".to_vec(), false));
+/// # Ok(())
+/// # }
+/// ```
+pub fn canonical_bases(seq: &[u8]) -> Result<(Vec<u8>, bool), NucleotideError> {
+    let (key, is_rc) = canonical_key_2bit(as_2bit(seq)?, seq.len())?;
+    let mut bases = Vec::with_capacity(seq.len());
+    from_2bit(key, seq.len(), &mut bases)?;
+    Ok((bases, is_rc))
+}
+
+/// Calculates the reverse complement of a block encoded sequence of any length.
+///
+/// The output is laid out like the output of [`encode`](crate::encode): every block
+/// holds 32 bases except the last, which holds the remainder with zeroed unused bits.
+///
+/// # Arguments
+///
+/// * `blocks` - The encoded sequence buffer
+/// * `total_len` - The length of the sequence in bases
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `blocks` is too short to hold `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{decode, encode_alloc, reverse_complement_blocks};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = encode_alloc(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACG")?;
+/// let rc = reverse_complement_blocks(&blocks, 36)?;
+///
+/// let mut dbuf = Vec::new();
+/// decode(&rc, 36, &mut dbuf)?;
+/// assert_eq!(&dbuf, b"CGTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTT");
+/// # Ok(())
+/// # }
+/// ```
+pub fn reverse_complement_blocks(
+    blocks: &[u64],
+    total_len: usize,
+) -> Result<Vec<u64>, NucleotideError> {
+    let n_chunks = total_len.div_ceil(32);
+    if blocks.len() < n_chunks {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+    if n_chunks == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Reverse complementing each full 32 base block in reverse order places the
+    // sequence at the start of a virtual 32-base aligned buffer, offset by the
+    // number of padding bases in the final input block.
+    let rem = total_len % 32;
+    let pad_bits = if rem == 0 { 0 } else { (32 - rem) * 2 };
+
+    let mut rc: Vec<u64> = blocks[..n_chunks]
+        .iter()
+        .rev()
+        .map(|&block| reverse_2bit_groups(!block))
+        .collect();
+
+    // Realign across block boundaries by shifting out the padding bases
+    if pad_bits > 0 {
+        for i in 0..n_chunks {
+            let next = rc.get(i + 1).map_or(0, |&b| b << (64 - pad_bits));
+            rc[i] = (rc[i] >> pad_bits) | next;
+        }
+    }
+
+    // Clear the unused bits of the final block
+    if rem > 0 {
+        rc[n_chunks - 1] &= window_mask(rem);
+    }
+
+    Ok(rc)
+}
+
+/// Lazily iterates the reverse complement of a block encoded sequence.
+///
+/// This is the streaming counterpart to [`reverse_complement_blocks`]: bases are
+/// complemented and yielded as ASCII nucleotides from the end of the sequence to
+/// the start, without allocating.
+///
+/// If `blocks` is too short to hold `total_len` bases the iterator yields nothing.
+///
+/// # Arguments
+///
+/// * `blocks` - The encoded sequence buffer
+/// * `total_len` - The length of the sequence in bases
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{encode_alloc, revcomp_bases};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = encode_alloc(b"AACGTTTG")?;
+/// let rc: Vec<u8> = revcomp_bases(&blocks, 8).collect();
+/// assert_eq!(&rc, b"CAAACGTT");
+/// # Ok(())
+/// # }
+/// ```
+pub fn revcomp_bases(blocks: &[u64], total_len: usize) -> impl Iterator<Item = u8> + '_ {
+    (0..total_len).rev().map_while(move |i| {
+        blocks
+            .get(i / 32)
+            .map(|&block| BASES[((!block >> ((i % 32) * 2)) & 0b11) as usize])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        as_2bit, as_2bit_with_alphabet, decode, encode_alloc, from_2bit_with_alphabet,
+        COMPLEMENT_CODE,
+    };
+
+    #[test]
+    fn test_reverse_complement() {
+        let test_cases: Vec<(&[u8], &[u8])> = vec![
+            (b"", b""),
+            (b"A", b"T"),
+            (b"C", b"G"),
+            (b"ACGT", b"ACGT"),
+            (b"AACCGGTTA", b"TAACCGGTT"),
+            (
+                b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAA",
+                b"TTAAAATTTTCCAGTCCTTAAAATTTTCCAGT",
+            ),
+        ];
+
+        for (seq, expected) in test_cases {
+            let packed = as_2bit(seq).unwrap();
+            let expected = as_2bit(expected).unwrap();
+            assert_eq!(reverse_complement_2bit(packed, seq.len()), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_reverse_complement_invalid_length() {
+        assert_eq!(
+            reverse_complement_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_is_reverse_complement() {
+        let a = as_2bit(b"GATTACA").unwrap();
+        let b = as_2bit(b"TGTAATC").unwrap();
+        assert_eq!(is_reverse_complement_2bit(a, b, 7), Ok(true));
+        assert_eq!(is_reverse_complement_2bit(b, a, 7), Ok(true));
+
+        let c = as_2bit(b"TGTAATG").unwrap();
+        assert_eq!(is_reverse_complement_2bit(a, c, 7), Ok(false));
+    }
+
+    #[test]
+    fn test_is_reverse_complement_ignores_unused_bits() {
+        let a = as_2bit(b"ACG").unwrap();
+        let b = as_2bit(b"CGT").unwrap() | (0b11 << 6);
+        assert_eq!(is_reverse_complement_2bit(a, b, 3), Ok(true));
+        assert_eq!(
+            is_reverse_complement_2bit(a, b, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_canonical_key() {
+        // Forward is smaller
+        let fwd = as_2bit(b"AACG").unwrap();
+        assert_eq!(canonical_key_2bit(fwd, 4), Ok((fwd, false)));
+
+        // Reverse complement is smaller
+        let fwd = as_2bit(b"CGTT").unwrap();
+        assert_eq!(
+            canonical_key_2bit(fwd, 4),
+            Ok((as_2bit(b"AACG").unwrap(), true))
+        );
+
+        // Palindromes stay on the forward strand
+        let fwd = as_2bit(b"ACGT").unwrap();
+        assert_eq!(canonical_key_2bit(fwd, 4), Ok((fwd, false)));
+    }
+
+    #[test]
+    fn test_canonical_key_errors() {
+        assert_eq!(
+            canonical_key_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    fn naive_reverse_complement(seq: &[u8]) -> Vec<u8> {
+        seq.iter()
+            .rev()
+            .map(|&b| match b {
+                b'A' => b'T',
+                b'C' => b'G',
+                b'G' => b'C',
+                b'T' => b'A',
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_reverse_complement_blocks() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCTAGGCTAACGT"; // 50 bases
+        let blocks = encode_alloc(seq).unwrap();
+
+        let rc = reverse_complement_blocks(&blocks, seq.len()).unwrap();
+        let expected = encode_alloc(&naive_reverse_complement(seq)).unwrap();
+        assert_eq!(rc, expected);
+    }
+
+    #[test]
+    fn test_reverse_complement_blocks_lengths() {
+        for len in 1..=130 {
+            let seq: Vec<u8> = (0..len).map(|i| b"ACGTTGCA"[(i * 5 + i / 3) % 8]).collect();
+            let blocks = encode_alloc(&seq).unwrap();
+
+            let rc = reverse_complement_blocks(&blocks, len).unwrap();
+            let mut dbuf = Vec::new();
+            decode(&rc, len, &mut dbuf).unwrap();
+            assert_eq!(
+                dbuf,
+                naive_reverse_complement(&seq),
+                "Failed for length {}",
+                len
+            );
+            assert_eq!(rc, encode_alloc(&dbuf).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_reverse_complement_blocks_invalid() {
+        assert_eq!(reverse_complement_blocks(&[], 0), Ok(Vec::new()));
+        assert_eq!(
+            reverse_complement_blocks(&[0], 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+
+    #[test]
+    fn test_revcomp_bases_matches_blocks() {
+        for len in [1, 31, 32, 33, 64, 77] {
+            let seq: Vec<u8> = (0..len).map(|i| b"ACGTTGCA"[(i * 5 + i / 3) % 8]).collect();
+            let blocks = encode_alloc(&seq).unwrap();
+
+            let rc = reverse_complement_blocks(&blocks, len).unwrap();
+            let mut expected = Vec::new();
+            decode(&rc, len, &mut expected).unwrap();
+            let streamed: Vec<u8> = revcomp_bases(&blocks, len).collect();
+            assert_eq!(streamed, expected, "Failed for length {}", len);
+        }
+    }
+
+    #[test]
+    fn test_revcomp_bases_short_buffer() {
+        assert_eq!(revcomp_bases(&[], 0).count(), 0);
+        assert_eq!(revcomp_bases(&[0], 33).count(), 0);
+    }
+
+    #[test]
+    fn test_as_2bit_both_strands() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTCC";
+        let (fwd, rc, len) = as_2bit_both_strands(seq).unwrap();
+        assert_eq!(len, seq.len());
+        assert_eq!(is_reverse_complement_2bit(fwd, rc, len), Ok(true));
+
+        let mut dbuf = Vec::new();
+        crate::from_2bit(fwd, len, &mut dbuf).unwrap();
+        assert_eq!(&dbuf, seq);
+
+        dbuf.clear();
+        crate::from_2bit(rc, len, &mut dbuf).unwrap();
+        assert_eq!(dbuf, naive_reverse_complement(seq));
+    }
+
+    #[test]
+    fn test_as_2bit_both_strands_errors() {
+        assert_eq!(as_2bit_both_strands(b""), Ok((0, 0, 0)));
+        assert_eq!(
+            as_2bit_both_strands(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+        assert_eq!(
+            as_2bit_both_strands(b"ACNT"),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+
+    #[test]
+    fn test_canonical_bases() {
+        // ACTT reverse complements to AAGT, which packs smaller
+        let (bases, is_rc) = canonical_bases(b"ACTT").unwrap();
+        assert_eq!((bases.as_slice(), is_rc), (&b"AAGT"[..], true));
+
+        let (bases, is_rc) = canonical_bases(b"AAGT").unwrap();
+        assert_eq!((bases.as_slice(), is_rc), (&b"AAGT"[..], false));
+
+        assert_eq!(canonical_bases(b""), Ok((Vec::new(), false)));
+        assert_eq!(
+            canonical_bases(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+
+    #[test]
+    fn test_find_palindromes() {
+        // EcoRI site embedded at position 7, with a second copy at the end
+        let seq = b"ACGTTACGAATTCCCAGAATTC";
+        assert_eq!(find_palindromes(seq, 6).unwrap(), vec![7, 16]);
+        assert_eq!(find_palindromes(b"gaattc", 6).unwrap(), vec![0]);
+        assert!(find_palindromes(b"GAATT", 6).unwrap().is_empty());
+
+        // ACGT is itself a palindrome, and so is every window of its repeat
+        assert_eq!(find_palindromes(b"ACGTACGT", 4).unwrap(), vec![0, 2, 4]);
+
+        assert_eq!(
+            find_palindromes(seq, 5),
+            Err(NucleotideError::InvalidLength(5))
+        );
+        assert_eq!(
+            find_palindromes(seq, 0),
+            Err(NucleotideError::InvalidLength(0))
+        );
+        assert_eq!(
+            find_palindromes(seq, 34),
+            Err(NucleotideError::InvalidLength(34))
+        );
+        assert_eq!(
+            find_palindromes(b"GAANTC", 6),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+
+    #[test]
+    fn test_is_palindrome_ignores_stray_bits() {
+        let packed = as_2bit(b"GAATTC").unwrap() | (0b11 << 12);
+        assert!(is_palindrome_2bit(packed, 6).unwrap());
+        assert!(is_palindrome_2bit(0, 0).unwrap());
+        assert!(is_palindrome_2bit(0, 33).is_err());
+    }
+
+    #[test]
+    fn test_reverse_complement_permuted_alphabet() {
+        let alphabet = *b"ACTG";
+        // Complement each code's base within the alphabet
+        let complement_map = alphabet.map(|base| {
+            let comp = match base {
+                b'A' => b'T',
+                b'C' => b'G',
+                b'G' => b'C',
+                _ => b'A',
+            };
+            alphabet.iter().position(|&b| b == comp).unwrap() as u8
+        });
+        assert_eq!(complement_map, [2, 3, 0, 1]);
+
+        let seq = b"GATTACAGGCTTACGATCGTACCGATAAGCTT";
+        for len in [0, 1, 7, 31, 32] {
+            let packed = as_2bit_with_alphabet(&seq[..len], alphabet).unwrap();
+            let rc = reverse_complement_2bit_alphabet(packed, len, complement_map).unwrap();
+
+            // Unpack, complement in ASCII, repack
+            let mut unpacked = Vec::new();
+            from_2bit_with_alphabet(packed, len, &mut unpacked, alphabet).unwrap();
+            let ascii_rc: Vec<u8> = unpacked
+                .iter()
+                .rev()
+                .map(|&base| match base {
+                    b'A' => b'T',
+                    b'C' => b'G',
+                    b'G' => b'C',
+                    _ => b'A',
+                })
+                .collect();
+            let expected = as_2bit_with_alphabet(&ascii_rc, alphabet).unwrap();
+            assert_eq!(rc, expected, "Failed for length {}", len);
+        }
+    }
+
+    #[test]
+    fn test_reverse_complement_alphabet_standard() {
+        let packed = as_2bit(b"ACGTTGCAAGG").unwrap() | (0b11 << 40);
+        assert_eq!(
+            reverse_complement_2bit_alphabet(packed, 11, COMPLEMENT_CODE),
+            reverse_complement_2bit(packed, 11)
+        );
+        assert_eq!(
+            reverse_complement_2bit_alphabet(0, 33, COMPLEMENT_CODE),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            reverse_complement_2bit_alphabet(0, 4, [3, 2, 4, 0]),
+            Err(NucleotideError::InvalidBase(4))
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::cmp::Ordering;
+
+use super::compare::cmp_2bit;
+use crate::utils::window_mask;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Circularly rotates a 2-bit packed sequence left by `rot` bases.
+///
+/// Bases that fall off the start of the sequence wrap around to its end, so
+/// rotating `ACGT` left by 1 gives `CGTA`. `rot` is taken modulo `len`, and
+/// bits beyond `len` are cleared in the result.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `rot` - The number of bases to rotate by
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, rotate_left_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACGT")?;
+/// assert_eq!(rotate_left_2bit(packed, 1, 4)?, as_2bit(b"CGTA")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rotate_left_2bit(packed: u64, rot: usize, len: usize) -> Result<u64, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if len == 0 {
+        return Ok(0);
+    }
+
+    let mask = window_mask(len);
+    let packed = packed & mask;
+    let rot = rot % len;
+    if rot == 0 {
+        return Ok(packed);
+    }
+
+    // The first `rot` bases sit in the low bits and move to the top of the window
+    Ok(((packed >> (rot * 2)) | (packed << ((len - rot) * 2))) & mask)
+}
+
+/// Circularly rotates a 2-bit packed sequence right by `rot` bases.
+///
+/// Bases that fall off the end of the sequence wrap around to its start, so
+/// rotating `ACGT` right by 1 gives `TACG`. This is the inverse of [`rotate_left_2bit`].
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `rot` - The number of bases to rotate by
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, rotate_right_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACGT")?;
+/// assert_eq!(rotate_right_2bit(packed, 1, 4)?, as_2bit(b"TACG")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rotate_right_2bit(packed: u64, rot: usize, len: usize) -> Result<u64, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if len == 0 {
+        return Ok(0);
+    }
+    rotate_left_2bit(packed, len - rot % len, len)
+}
+
+/// Returns the lexicographically smallest rotation of a 2-bit packed sequence.
+///
+/// Rotations are compared with [`cmp_2bit`], using the nucleotide ordering
+/// `A < C < G < T`. All rotations of a circular sequence map to the same value,
+/// which makes this suitable for canonicalizing circular contexts.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, min_rotation_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"GTAC")?;
+/// assert_eq!(min_rotation_2bit(packed, 4)?, as_2bit(b"ACGT")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn min_rotation_2bit(packed: u64, len: usize) -> Result<u64, NucleotideError> {
+    let mut best = rotate_left_2bit(packed, 0, len)?;
+    for rot in 1..len {
+        let candidate = rotate_left_2bit(packed, rot, len)?;
+        if cmp_2bit(candidate, best, len)? == Ordering::Less {
+            best = candidate;
+        }
+    }
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_rotate_left() {
+        let packed = as_2bit(b"ACGT").unwrap();
+        assert_eq!(
+            rotate_left_2bit(packed, 1, 4),
+            Ok(as_2bit(b"CGTA").unwrap())
+        );
+        assert_eq!(
+            rotate_left_2bit(packed, 2, 4),
+            Ok(as_2bit(b"GTAC").unwrap())
+        );
+        assert_eq!(rotate_left_2bit(packed, 4, 4), Ok(packed));
+        assert_eq!(
+            rotate_left_2bit(packed, 5, 4),
+            Ok(as_2bit(b"CGTA").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let packed = as_2bit(b"ACGT").unwrap();
+        assert_eq!(
+            rotate_right_2bit(packed, 1, 4),
+            Ok(as_2bit(b"TACG").unwrap())
+        );
+        assert_eq!(rotate_right_2bit(packed, 0, 4), Ok(packed));
+        for rot in 0..10 {
+            let left = rotate_left_2bit(packed, rot, 4).unwrap();
+            assert_eq!(rotate_right_2bit(left, rot, 4), Ok(packed));
+        }
+    }
+
+    #[test]
+    fn test_rotate_full_width() {
+        let seq = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCC";
+        let packed = as_2bit(seq).unwrap();
+        let mut expected = seq[3..].to_vec();
+        expected.extend_from_slice(&seq[..3]);
+        assert_eq!(
+            rotate_left_2bit(packed, 3, 32),
+            Ok(as_2bit(&expected).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_rotate_edge_cases() {
+        assert_eq!(rotate_left_2bit(u64::MAX, 3, 0), Ok(0));
+        assert_eq!(rotate_right_2bit(u64::MAX, 3, 0), Ok(0));
+        assert_eq!(
+            rotate_left_2bit(0, 1, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            rotate_right_2bit(0, 1, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+
+        // Bits beyond the window are cleared
+        let packed = as_2bit(b"ACGT").unwrap() | (0b11 << 8);
+        assert_eq!(
+            rotate_left_2bit(packed, 1, 4),
+            Ok(as_2bit(b"CGTA").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_min_rotation() {
+        let expected = as_2bit(b"ACGT").unwrap();
+        let packed = as_2bit(b"CGTA").unwrap();
+        for rot in 0..4 {
+            let rotation = rotate_left_2bit(packed, rot, 4).unwrap();
+            assert_eq!(min_rotation_2bit(rotation, 4), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_min_rotation_edge_cases() {
+        assert_eq!(min_rotation_2bit(0, 0), Ok(0));
+        let packed = as_2bit(b"TTGTT").unwrap();
+        assert_eq!(min_rotation_2bit(packed, 5), Ok(as_2bit(b"GTTTT").unwrap()));
+        assert_eq!(
+            min_rotation_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use super::hamming::masked_distance;
+use crate::utils::window_mask;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Scores the ungapped alignment of two equal-length 2-bit packed sequences.
+///
+/// Every position is either a match or a mismatch, so the score is
+/// `matches * match_score - mismatches * mismatch_penalty`, saturating at the
+/// bounds of `i32`. Bits beyond `len` bases are ignored.
+///
+/// # Arguments
+///
+/// * `a` - A u64 containing the first 2-bit packed sequence
+/// * `b` - A u64 containing the second 2-bit packed sequence
+/// * `len` - The number of bases in both sequences
+/// * `match_score` - The score added for each matching position
+/// * `mismatch_penalty` - The score subtracted for each mismatching position
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, ungapped_score_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = as_2bit(b"ACGTACGT")?;
+/// let b = as_2bit(b"ACGAACGA")?;
+/// assert_eq!(ungapped_score_2bit(a, b, 8, 1, 1)?, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn ungapped_score_2bit(
+    a: u64,
+    b: u64,
+    len: usize,
+    match_score: i32,
+    mismatch_penalty: i32,
+) -> Result<i32, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let mismatches = masked_distance(a, b, window_mask(len)) as i32;
+    let matches = len as i32 - mismatches;
+    Ok(matches
+        .saturating_mul(match_score)
+        .saturating_sub(mismatches.saturating_mul(mismatch_penalty)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_ungapped_score_2bit() {
+        // 12 matches and 4 mismatches
+        let a = as_2bit(b"ACGTACGTACGTACGT").unwrap();
+        let b = as_2bit(b"TCGTACCTACGAACGG").unwrap();
+        assert_eq!(ungapped_score_2bit(a, b, 16, 5, 4), Ok(12 * 5 - 4 * 4));
+        assert_eq!(ungapped_score_2bit(a, b, 16, 1, 3), Ok(0));
+        assert_eq!(ungapped_score_2bit(a, b, 16, 2, -1), Ok(28));
+        assert_eq!(ungapped_score_2bit(a, a, 16, 2, 100), Ok(32));
+    }
+
+    #[test]
+    fn test_ungapped_score_2bit_edge_cases() {
+        let a = as_2bit(b"ACGT").unwrap();
+        // Bits past the sequence are ignored
+        assert_eq!(ungapped_score_2bit(a, a | (1 << 40), 4, 1, 1), Ok(4));
+        assert_eq!(ungapped_score_2bit(0, u64::MAX, 0, 1, 1), Ok(0));
+        assert_eq!(
+            ungapped_score_2bit(0, u64::MAX, 32, 1, i32::MAX),
+            Ok(i32::MIN + 1)
+        );
+        assert_eq!(
+            ungapped_score_2bit(0, 0, 33, 1, 1),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,62 @@
+/// Returns the number of bytes needed to store a sequence as 2-bit blocks.
+///
+/// Each u64 block holds up to 32 bases, so this is `ceil(seq_len / 32) * 8`.
+///
+/// # Arguments
+///
+/// * `seq_len` - The number of bases in the sequence
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::packed_size_bytes;
+///
+/// assert_eq!(packed_size_bytes(32), 8);
+/// assert_eq!(packed_size_bytes(33), 16);
+/// ```
+pub fn packed_size_bytes(seq_len: usize) -> usize {
+    seq_len.div_ceil(32) * 8
+}
+
+/// Returns how many times smaller the 2-bit blocks are than the ASCII sequence.
+///
+/// The ratio is `seq_len / packed_size_bytes(seq_len)`. An empty sequence
+/// takes no space either way and has a ratio of 1.0.
+///
+/// # Arguments
+///
+/// * `seq_len` - The number of bases in the sequence
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::compression_ratio;
+///
+/// assert_eq!(compression_ratio(64), 4.0);
+/// assert_eq!(compression_ratio(1), 0.125);
+/// ```
+pub fn compression_ratio(seq_len: usize) -> f64 {
+    if seq_len == 0 {
+        return 1.0;
+    }
+    seq_len as f64 / packed_size_bytes(seq_len) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packed_size_bytes() {
+        assert_eq!(packed_size_bytes(0), 0);
+        assert_eq!(packed_size_bytes(32), 8);
+        assert_eq!(packed_size_bytes(33), 16);
+    }
+
+    #[test]
+    fn test_compression_ratio() {
+        assert_eq!(compression_ratio(0), 1.0);
+        assert_eq!(compression_ratio(32), 4.0);
+        assert_eq!(compression_ratio(33), 33.0 / 16.0);
+    }
+}
@@ -0,0 +1,437 @@
+use super::hash::{hash_kmer, KmerHasher};
+use super::kmer::{for_each_kmer, for_each_kmer_blocks};
+use super::revcomp::reverse_complement_2bit;
+use crate::NucleotideError;
+use std::collections::{BTreeSet, HashMap};
+
+/// Computes a bottom-s MinHash sketch of the canonical k-mers in a sequence.
+///
+/// Each k-mer is canonicalized as the smaller of itself and its reverse complement,
+/// hashed, and the `sketch_size` smallest distinct hashes are kept.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `k` - The k-mer size (1..=32)
+/// * `sketch_size` - The maximum number of hashes to keep
+///
+/// # Returns
+///
+/// Returns the sketch as hashes in ascending order.
+/// The sketch is shorter than `sketch_size` if the sequence has fewer distinct k-mers.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{minhash_similarity, minhash_sketch};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = minhash_sketch(b"ACGTTGCAAGGCTTAGCTAGGATCCA", 5, 16)?;
+/// let b = minhash_sketch(b"TGGATCCTAGCTAAGCCTTGCAACGT", 5, 16)?; // reverse complement
+/// assert_eq!(minhash_similarity(&a, &b), 1.0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn minhash_sketch(
+    seq: &[u8],
+    k: usize,
+    sketch_size: usize,
+) -> Result<Vec<u64>, NucleotideError> {
+    let mut minima = BTreeSet::new();
+    for_each_kmer(seq, k, |kmer| {
+        insert_canonical(&mut minima, kmer, k, sketch_size)
+    })?;
+    Ok(minima.into_iter().collect())
+}
+
+/// Adds the hash of a canonical k-mer to a bottom-s sketch bounded to `sketch_size` hashes.
+#[inline]
+fn insert_canonical(minima: &mut BTreeSet<u64>, kmer: u64, k: usize, sketch_size: usize) {
+    let rc = reverse_complement_2bit(kmer, k).unwrap_or(kmer);
+    minima.insert(hash_kmer(kmer.min(rc)));
+    if minima.len() > sketch_size {
+        minima.pop_last();
+    }
+}
+
+/// Computes a bottom-s MinHash sketch of the canonical k-mers in a block encoded sequence.
+fn minhash_sketch_blocks(
+    blocks: &[u64],
+    total_len: usize,
+    k: usize,
+    sketch_size: usize,
+) -> Result<Vec<u64>, NucleotideError> {
+    let mut minima = BTreeSet::new();
+    for_each_kmer_blocks(blocks, total_len, k, |kmer| {
+        insert_canonical(&mut minima, kmer, k, sketch_size)
+    })?;
+    Ok(minima.into_iter().collect())
+}
+
+/// Estimates the Jaccard similarity of the canonical k-mers of two block encoded sequences.
+///
+/// K-mers are rolled directly across the blocks, so long sequences are never
+/// unpacked. Each sequence is reduced to a bottom-`sketch` MinHash sketch as in
+/// [`minhash_sketch`], and the estimate is computed by [`minhash_similarity`].
+///
+/// # Arguments
+///
+/// * `a` - The first encoded sequence buffer
+/// * `a_len` - The length of the first sequence in bases
+/// * `b` - The second encoded sequence buffer
+/// * `b_len` - The length of the second sequence in bases
+/// * `k` - The k-mer size (1..=32)
+/// * `sketch` - The maximum number of hashes to keep per sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32, or if
+/// either buffer is too short to hold its length.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{encode_alloc, jaccard_blocks};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCTAGG";
+/// let blocks = encode_alloc(seq)?;
+/// assert_eq!(jaccard_blocks(&blocks, seq.len(), &blocks, seq.len(), 11, 32)?, 1.0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn jaccard_blocks(
+    a: &[u64],
+    a_len: usize,
+    b: &[u64],
+    b_len: usize,
+    k: usize,
+    sketch: usize,
+) -> Result<f64, NucleotideError> {
+    let a_sketch = minhash_sketch_blocks(a, a_len, k, sketch)?;
+    let b_sketch = minhash_sketch_blocks(b, b_len, k, sketch)?;
+    Ok(minhash_similarity(&a_sketch, &b_sketch))
+}
+
+/// Estimates the Jaccard similarity of two sequences from their MinHash sketches.
+///
+/// Both sketches must be sorted in ascending order, as produced by [`minhash_sketch`].
+/// The estimate is the fraction of the `s` smallest hashes of the union of both
+/// sketches that are present in both, where `s` is the size of the smaller sketch.
+///
+/// Two empty sketches are considered identical (`1.0`),
+/// while an empty sketch has no similarity to a non-empty one (`0.0`).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{minhash_similarity, minhash_sketch};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let a = minhash_sketch(b"AAAAAAAAAAAA", 4, 8)?;
+/// let b = minhash_sketch(b"ACACACACACAC", 4, 8)?;
+/// assert_eq!(minhash_similarity(&a, &b), 0.0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn minhash_similarity(a: &[u64], b: &[u64]) -> f64 {
+    let sketch_size = a.len().min(b.len());
+    if sketch_size == 0 {
+        return if a.is_empty() && b.is_empty() {
+            1.0
+        } else {
+            0.0
+        };
+    }
+
+    // Walk the union of both sorted sketches in ascending order
+    let (mut i, mut j) = (0, 0);
+    let mut shared = 0;
+    for _ in 0..sketch_size {
+        match (a.get(i), b.get(j)) {
+            (Some(x), Some(y)) if x == y => {
+                shared += 1;
+                i += 1;
+                j += 1;
+            }
+            (Some(x), Some(y)) if x < y => i += 1,
+            (Some(_), Some(_)) => j += 1,
+            _ => break,
+        }
+    }
+    shared as f64 / sketch_size as f64
+}
+
+/// Counts the canonical k-mers of a sequence.
+fn canonical_kmer_counts(
+    seq: &[u8],
+    k: usize,
+) -> Result<HashMap<u64, u32, KmerHasher>, NucleotideError> {
+    let mut counts = HashMap::default();
+    for_each_kmer(seq, k, |kmer| {
+        let rc = reverse_complement_2bit(kmer, k).unwrap_or(kmer);
+        *counts.entry(kmer.min(rc)).or_insert(0) += 1;
+    })?;
+    Ok(counts)
+}
+
+/// Computes the cosine similarity of the canonical k-mer profiles of two sequences.
+///
+/// Each sequence is reduced to a vector of canonical k-mer counts, and the
+/// similarity is the dot product of the two vectors divided by the product of
+/// their norms. This is an alignment-free measure that ignores strand.
+///
+/// Two empty profiles are considered identical (`1.0`),
+/// while an empty profile has no similarity to a non-empty one (`0.0`).
+///
+/// # Arguments
+///
+/// * `a` - The first sequence of ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `b` - The second sequence of ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `k` - The k-mer size (1..=32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if either sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::kmer_cosine;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let similarity = kmer_cosine(b"ACGTTGCAAGG", b"CCTTGCAACGT", 4)?; // reverse complement
+/// assert!((similarity - 1.0).abs() < 1e-12);
+/// # Ok(())
+/// # }
+/// ```
+pub fn kmer_cosine(a: &[u8], b: &[u8], k: usize) -> Result<f64, NucleotideError> {
+    let a_counts = canonical_kmer_counts(a, k)?;
+    let b_counts = canonical_kmer_counts(b, k)?;
+    if a_counts.is_empty() || b_counts.is_empty() {
+        return Ok(if a_counts.is_empty() && b_counts.is_empty() {
+            1.0
+        } else {
+            0.0
+        });
+    }
+
+    // Iterate the smaller profile for the shared keys
+    let (small, large) = if a_counts.len() <= b_counts.len() {
+        (&a_counts, &b_counts)
+    } else {
+        (&b_counts, &a_counts)
+    };
+    let dot: f64 = small
+        .iter()
+        .filter_map(|(kmer, &x)| large.get(kmer).map(|&y| x as f64 * y as f64))
+        .sum();
+
+    let norm = |counts: &HashMap<u64, u32, KmerHasher>| {
+        counts
+            .values()
+            .map(|&x| x as f64 * x as f64)
+            .sum::<f64>()
+            .sqrt()
+    };
+    Ok(dot / (norm(&a_counts) * norm(&b_counts)))
+}
+
+/// Measures the complexity of a sequence as its ratio of distinct to total canonical k-mers.
+///
+/// Repetitive, low-complexity sequences reuse the same few k-mers and score close
+/// to 0, while sequences without repeated k-mers score 1.0. Sequences with at most
+/// one k-mer are defined to score 1.0.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `k` - The k-mer size (1..=32)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `k` is 0 or greater than 32.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::kmer_complexity;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(kmer_complexity(b"AAAAAAAAAAAA", 3)?, 0.1);
+/// assert_eq!(kmer_complexity(b"GATTACA", 3)?, 1.0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn kmer_complexity(seq: &[u8], k: usize) -> Result<f64, NucleotideError> {
+    let counts = canonical_kmer_counts(seq, k)?;
+    let total: u32 = counts.values().sum();
+    if total <= 1 {
+        return Ok(1.0);
+    }
+    Ok(counts.len() as f64 / total as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minhash_identical() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCTAGGCTAACGT";
+        let a = minhash_sketch(seq, 11, 20).unwrap();
+        let b = minhash_sketch(seq, 11, 20).unwrap();
+        assert_eq!(a.len(), 20);
+        assert_eq!(minhash_similarity(&a, &b), 1.0);
+    }
+
+    #[test]
+    fn test_minhash_sorted_and_distinct() {
+        let seq = b"ACGTACGTACGTACGTACGTACGTACGT";
+        let sketch = minhash_sketch(seq, 4, 100).unwrap();
+
+        // ACGT, CGTA, GTAC, TACG collapse into three canonical k-mers
+        assert_eq!(sketch.len(), 3);
+        assert!(sketch.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_minhash_partial_overlap() {
+        let a = minhash_sketch(b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAA", 8, 50).unwrap();
+        let b = minhash_sketch(b"ACTGGAAAATTTTAAGCCGTAGCTAGGCTAAC", 8, 50).unwrap();
+        let similarity = minhash_similarity(&a, &b);
+        assert!(similarity > 0.0 && similarity < 1.0);
+    }
+
+    #[test]
+    fn test_minhash_empty() {
+        let a = minhash_sketch(b"ACG", 4, 10).unwrap();
+        assert!(a.is_empty());
+        assert_eq!(minhash_similarity(&a, &a), 1.0);
+
+        let b = minhash_sketch(b"ACGT", 4, 10).unwrap();
+        assert_eq!(minhash_similarity(&a, &b), 0.0);
+    }
+
+    #[test]
+    fn test_minhash_errors() {
+        assert_eq!(
+            minhash_sketch(b"ACGT", 33, 10),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            minhash_sketch(b"ACGNACGT", 4, 10),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+
+    #[test]
+    fn test_kmer_cosine_identical() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCTAGGCTAACGT";
+        let similarity = kmer_cosine(seq, seq, 7).unwrap();
+        assert!((similarity - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kmer_cosine_disjoint() {
+        assert_eq!(kmer_cosine(b"AAAAAAAA", b"ACACACAC", 3), Ok(0.0));
+    }
+
+    #[test]
+    fn test_kmer_cosine_partial() {
+        // AAA: 2 and AAC: 1 against AAA: 1 and AAC: 1
+        let similarity = kmer_cosine(b"AAAAC", b"AAAC", 3).unwrap();
+        let expected = 3.0 / (5.0f64.sqrt() * 2.0f64.sqrt());
+        assert!((similarity - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kmer_cosine_edge_cases() {
+        assert_eq!(kmer_cosine(b"AC", b"GT", 3), Ok(1.0));
+        assert_eq!(kmer_cosine(b"AC", b"ACGT", 3), Ok(0.0));
+        assert_eq!(
+            kmer_cosine(b"ACGT", b"ACGT", 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            kmer_cosine(b"ACGT", b"ACNT", 2),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+
+    #[test]
+    fn test_jaccard_blocks_known_overlap() {
+        // Distinct 12-mers, so that every k-mer of the two sequences is unique
+        let shared = b"ACTGGAAATTCAGGCTAGCATGCAACGTTAGC"; // 32 bases
+        let mut a = shared.to_vec();
+        a.extend_from_slice(b"TTGACCGATAGGCATCCGTAGTTCAG"); // 58 bases
+        a.extend_from_slice(b"CA");
+        let mut b = shared.to_vec();
+        b.extend_from_slice(b"GCCATATGCGGTACTCTAAGCGATGC");
+        b.extend_from_slice(b"GT");
+        assert_eq!((a.len(), b.len()), (60, 60));
+
+        let k = 12;
+        let a_blocks = crate::encode_alloc(&a).unwrap();
+        let b_blocks = crate::encode_alloc(&b).unwrap();
+
+        // With a sketch larger than the k-mer sets the estimate is close to the
+        // true Jaccard index: 21 shared of 49 k-mers per sequence, so 21 / (49 + 49 - 21)
+        let exact = jaccard_blocks(&a_blocks, 60, &b_blocks, 60, k, 1000).unwrap();
+        assert!((exact - 21.0 / 77.0).abs() < 0.05, "{}", exact);
+
+        // Matches the ASCII sketches
+        let a_sketch = minhash_sketch(&a, k, 16).unwrap();
+        let b_sketch = minhash_sketch(&b, k, 16).unwrap();
+        assert_eq!(
+            jaccard_blocks(&a_blocks, 60, &b_blocks, 60, k, 16),
+            Ok(minhash_similarity(&a_sketch, &b_sketch))
+        );
+    }
+
+    #[test]
+    fn test_jaccard_blocks_errors() {
+        let blocks = crate::encode_alloc(b"ACGT").unwrap();
+        assert_eq!(
+            jaccard_blocks(&blocks, 4, &blocks, 4, 33, 8),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            jaccard_blocks(&blocks, 40, &blocks, 4, 3, 8),
+            Err(NucleotideError::InvalidLength(40))
+        );
+    }
+
+    #[test]
+    fn test_kmer_complexity_homopolymer() {
+        let complexity = kmer_complexity(&[b'A'; 50], 5).unwrap();
+        assert_eq!(complexity, 1.0 / 46.0);
+    }
+
+    #[test]
+    fn test_kmer_complexity_high() {
+        let seq = b"ACTGGAAATTCAGGCTAGCATGCAACGTTAGCTTGACCGATAGG";
+        let complexity = kmer_complexity(seq, 11).unwrap();
+        assert!(complexity > 0.95, "{}", complexity);
+    }
+
+    #[test]
+    fn test_kmer_complexity_edge_cases() {
+        assert_eq!(kmer_complexity(b"", 3), Ok(1.0));
+        assert_eq!(kmer_complexity(b"ACG", 3), Ok(1.0));
+        assert_eq!(
+            kmer_complexity(b"ACGT", 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
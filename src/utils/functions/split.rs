@@ -1,4 +1,6 @@
-use crate::NucleotideError;
+use super::kmer::kmer_at_blocks;
+use crate::utils::window_mask;
+use crate::{NucleotideError, MAX_BASES};
 
 /// Splits a packed nucleotide sequence into two subsequences at the given index.
 ///
@@ -69,12 +71,7 @@ pub fn split_packed(
     }
 
     // Handle the split chunk
-    let split_mask = if bit_idx == 0 {
-        0
-    } else {
-        (1u64 << bit_idx) - 1
-    };
-    lbuf.push(ebuf[chunk_idx] & split_mask);
+    lbuf.push(ebuf[chunk_idx] & window_mask(idx % 32));
 
     // Handle remaining bits for right buffer
     let right_shift = bit_idx;
@@ -101,10 +98,75 @@ pub fn split_packed(
     Ok(())
 }
 
+/// Tiles a block encoded sequence into overlapping packed windows.
+///
+/// Tiles start every `tile - overlap` bases from the start of the sequence and
+/// stop at the first tile that reaches the end, which may be shorter than
+/// `tile`. An empty sequence has no tiles.
+///
+/// # Arguments
+///
+/// * `blocks` - The encoded sequence buffer
+/// * `total_len` - The length of the sequence in bases
+/// * `tile` - The number of bases in each tile (1..=32)
+/// * `overlap` - The number of bases shared by consecutive tiles
+///
+/// # Returns
+///
+/// Returns each tile as its packed value and number of bases.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `tile` is 0 or greater than 32,
+/// if `overlap` is not less than `tile`, or if `blocks` is too short to hold
+/// `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, encode_alloc, tiles_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = encode_alloc(b"ACGTACG")?;
+/// let tiles = tiles_2bit(&blocks, 7, 4, 1)?;
+/// assert_eq!(tiles, vec![(as_2bit(b"ACGT")?, 4), (as_2bit(b"TACG")?, 4)]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn tiles_2bit(
+    blocks: &[u64],
+    total_len: usize,
+    tile: usize,
+    overlap: usize,
+) -> Result<Vec<(u64, usize)>, NucleotideError> {
+    if tile == 0 || tile > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(tile));
+    }
+    if overlap >= tile {
+        return Err(NucleotideError::InvalidLength(overlap));
+    }
+    if blocks.len() < total_len.div_ceil(32) {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+
+    let step = tile - overlap;
+    let mut tiles = Vec::new();
+    let mut start = 0;
+    while start < total_len {
+        let len = tile.min(total_len - start);
+        tiles.push((kmer_at_blocks(blocks, total_len, start, len)?, len));
+        if start + len == total_len {
+            break;
+        }
+        start += step;
+    }
+    Ok(tiles)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{decode, encode};
+    use crate::{as_2bit, decode, encode, encode_alloc};
 
     #[test]
     fn test_split_basic() {
@@ -223,4 +285,50 @@ mod tests {
         // Out of bounds index
         assert!(split_packed(&ebuf, seq.len(), seq.len() + 1, &mut lbuf, &mut rbuf).is_err());
     }
+
+    #[test]
+    fn test_tiles_2bit() {
+        let seq: Vec<u8> = (0..50)
+            .map(|i| b"ACGTTGCAAG"[(i * 7 + i / 5) % 10])
+            .collect();
+        let blocks = encode_alloc(&seq).unwrap();
+
+        let tiles = tiles_2bit(&blocks, 50, 20, 5).unwrap();
+        let expected: Vec<(u64, usize)> = [0..20, 15..35, 30..50]
+            .into_iter()
+            .map(|range| (as_2bit(&seq[range]).unwrap(), 20))
+            .collect();
+        assert_eq!(tiles, expected);
+
+        // The last tile is shorter when the step does not line up with the end
+        let tiles = tiles_2bit(&blocks, 50, 20, 2).unwrap();
+        assert_eq!(tiles.len(), 3);
+        assert_eq!(tiles[2], (as_2bit(&seq[36..]).unwrap(), 14));
+    }
+
+    #[test]
+    fn test_tiles_2bit_edge_cases() {
+        let blocks = encode_alloc(b"ACGT").unwrap();
+        assert_eq!(tiles_2bit(&[], 0, 4, 0), Ok(vec![]));
+        assert_eq!(
+            tiles_2bit(&blocks, 4, 32, 0),
+            Ok(vec![(as_2bit(b"ACGT").unwrap(), 4)])
+        );
+        assert_eq!(
+            tiles_2bit(&blocks, 4, 0, 0),
+            Err(NucleotideError::InvalidLength(0))
+        );
+        assert_eq!(
+            tiles_2bit(&blocks, 4, 33, 0),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            tiles_2bit(&blocks, 4, 4, 4),
+            Err(NucleotideError::InvalidLength(4))
+        );
+        assert_eq!(
+            tiles_2bit(&blocks, 40, 4, 1),
+            Err(NucleotideError::InvalidLength(40))
+        );
+    }
 }
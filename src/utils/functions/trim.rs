@@ -0,0 +1,102 @@
+use crate::{NucleotideError, MAX_BASES};
+
+/// Removes the bases of a 2-bit packed sequence that are not marked to keep.
+///
+/// Every position where `keep[i]` is false is dropped and the remaining bases
+/// are compacted in order, so this trims both ends as well as any interior
+/// positions. Bits beyond the new length are cleared.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+/// * `keep` - Whether to keep each base, one entry per base
+///
+/// # Returns
+///
+/// Returns the compacted packed value and its length in bases.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32 or
+/// `keep` does not have exactly `len` entries.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, trim_by_mask_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACGT")?;
+/// let trimmed = trim_by_mask_2bit(packed, 4, &[false, true, true, false])?;
+/// assert_eq!(trimmed, (as_2bit(b"CG")?, 2));
+/// # Ok(())
+/// # }
+/// ```
+pub fn trim_by_mask_2bit(
+    packed: u64,
+    len: usize,
+    keep: &[bool],
+) -> Result<(u64, usize), NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if keep.len() != len {
+        return Err(NucleotideError::InvalidLength(keep.len()));
+    }
+
+    let mut trimmed = 0u64;
+    let mut new_len = 0;
+    for (i, _) in keep.iter().enumerate().filter(|(_, &k)| k) {
+        trimmed |= ((packed >> (i * 2)) & 0b11) << (new_len * 2);
+        new_len += 1;
+    }
+    Ok((trimmed, new_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_trim_ends() {
+        let packed = as_2bit(b"ACGT").unwrap();
+        assert_eq!(
+            trim_by_mask_2bit(packed, 4, &[false, true, true, false]),
+            Ok((as_2bit(b"CG").unwrap(), 2))
+        );
+    }
+
+    #[test]
+    fn test_trim_throughout() {
+        let seq = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA";
+        let packed = as_2bit(seq).unwrap();
+        let keep: Vec<bool> = (0..seq.len()).map(|i| i % 3 != 1).collect();
+        let expected: Vec<u8> = seq
+            .iter()
+            .zip(&keep)
+            .filter(|(_, &k)| k)
+            .map(|(&b, _)| b)
+            .collect();
+        assert_eq!(
+            trim_by_mask_2bit(packed, seq.len(), &keep),
+            Ok((as_2bit(&expected).unwrap(), expected.len()))
+        );
+    }
+
+    #[test]
+    fn test_trim_edge_cases() {
+        let packed = as_2bit(b"ACGT").unwrap();
+        assert_eq!(trim_by_mask_2bit(packed, 4, &[false; 4]), Ok((0, 0)));
+        assert_eq!(trim_by_mask_2bit(packed, 4, &[true; 4]), Ok((packed, 4)));
+        assert_eq!(
+            trim_by_mask_2bit(packed, 4, &[true; 3]),
+            Err(NucleotideError::InvalidLength(3))
+        );
+        assert_eq!(
+            trim_by_mask_2bit(packed, 33, &[true; 33]),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
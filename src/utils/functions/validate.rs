@@ -0,0 +1,415 @@
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+use std::arch::x86_64::*;
+
+use crate::error::ValidationError;
+use crate::utils::packing::base_to_2bit;
+use crate::utils::window_mask;
+use crate::{NucleotideError, MAX_BASES};
+use std::io::{ErrorKind, Read};
+
+/// Size of the buffer used to read from the stream
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Validates a stream of nucleotides without loading it into memory.
+///
+/// The reader is consumed in fixed-size chunks.
+/// When `allow_whitespace` is set, ASCII whitespace (`\n`, `\r`, `\t`, space) is
+/// allowed anywhere in the stream so that line-wrapped sequences validate, but is
+/// not counted as a base.
+///
+/// # Arguments
+///
+/// * `reader` - The source of ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `allow_whitespace` - Whether to skip whitespace instead of rejecting it
+///
+/// # Returns
+///
+/// Returns the total number of bases in the stream.
+///
+/// # Errors
+///
+/// Returns `ValidationError::InvalidBase` with the global byte offset of the first
+/// byte that is neither a nucleotide nor allowed whitespace.
+///
+/// Returns `ValidationError::Io` if reading from the stream fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{validate_reader, ValidationError};
+/// use std::io::Cursor;
+///
+/// let n_bases = validate_reader(&mut Cursor::new(b"ACGT\nacgt\n"), true).unwrap();
+/// assert_eq!(n_bases, 8);
+///
+/// let err = validate_reader(&mut Cursor::new(b"ACGT\nACNT\n"), true).unwrap_err();
+/// assert!(matches!(err, ValidationError::InvalidBase { offset: 7, base: b'N' }));
+///
+/// let err = validate_reader(&mut Cursor::new(b"ACGT\nacgt\n"), false).unwrap_err();
+/// assert!(matches!(err, ValidationError::InvalidBase { offset: 4, base: b'\n' }));
+/// ```
+pub fn validate_reader<R: Read>(
+    reader: &mut R,
+    allow_whitespace: bool,
+) -> Result<usize, ValidationError> {
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut offset = 0;
+    let mut n_bases = 0;
+
+    loop {
+        let n_read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        for (i, &byte) in buffer[..n_read].iter().enumerate() {
+            match byte {
+                byte if is_nucleotide(byte) => n_bases += 1,
+                b'\n' | b'\r' | b'\t' | b' ' if allow_whitespace => {}
+                base => {
+                    return Err(ValidationError::InvalidBase {
+                        offset: offset + i,
+                        base,
+                    })
+                }
+            }
+        }
+        offset += n_read;
+    }
+
+    Ok(n_bases)
+}
+
+/// Returns true if the byte is a nucleotide (A,C,G,T, case insensitive)
+#[inline(always)]
+fn is_nucleotide(byte: u8) -> bool {
+    matches!(byte, b'A' | b'C' | b'G' | b'T' | b'a' | b'c' | b'g' | b't')
+}
+
+/// Appends every invalid base of `seq` to `out`, offsetting positions by `offset`
+#[inline]
+fn scan_invalid(seq: &[u8], offset: usize, out: &mut Vec<(usize, u8)>) {
+    out.extend(
+        seq.iter()
+            .enumerate()
+            .filter(|(_, &byte)| !is_nucleotide(byte))
+            .map(|(i, &byte)| (offset + i, byte)),
+    );
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+#[inline]
+unsafe fn all_invalid_positions_avx2(seq: &[u8], out: &mut Vec<(usize, u8)>) {
+    // Clearing bit 5 maps lowercase acgt onto uppercase ACGT and no other byte onto them
+    let case_mask = _mm256_set1_epi8(!0x20u8 as i8);
+    let a = _mm256_set1_epi8(b'A' as i8);
+    let c = _mm256_set1_epi8(b'C' as i8);
+    let g = _mm256_set1_epi8(b'G' as i8);
+    let t = _mm256_set1_epi8(b'T' as i8);
+
+    let mut chunks = seq.chunks_exact(32);
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        let bytes = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+        let upper = _mm256_and_si256(bytes, case_mask);
+
+        let valid = _mm256_or_si256(
+            _mm256_or_si256(_mm256_cmpeq_epi8(upper, a), _mm256_cmpeq_epi8(upper, c)),
+            _mm256_or_si256(_mm256_cmpeq_epi8(upper, g), _mm256_cmpeq_epi8(upper, t)),
+        );
+
+        // Only pinpoint offenders in blocks with at least one invalid lane
+        if _mm256_movemask_epi8(valid) != -1 {
+            scan_invalid(chunk, i * 32, out);
+        }
+    }
+
+    let remainder = chunks.remainder();
+    scan_invalid(remainder, seq.len() - remainder.len(), out);
+}
+
+/// Finds every non-nucleotide byte in a sequence.
+///
+/// Unlike packing, which stops at the first invalid base, this reports all of
+/// them for use in detailed QC reports.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice expected to contain ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns the `(index, byte)` pairs of every invalid base in order of position.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::all_invalid_positions;
+///
+/// let invalid = all_invalid_positions(b"ACNGTRA");
+/// assert_eq!(invalid, vec![(2, b'N'), (5, b'R')]);
+/// ```
+pub fn all_invalid_positions(seq: &[u8]) -> Vec<(usize, u8)> {
+    let mut out = Vec::new();
+
+    #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+    if is_x86_feature_detected!("avx2") {
+        unsafe { all_invalid_positions_avx2(seq, &mut out) };
+        return out;
+    }
+
+    scan_invalid(seq, 0, &mut out);
+    out
+}
+
+/// Validates every sequence in a batch without stopping at the first failure.
+///
+/// # Arguments
+///
+/// * `seqs` - The sequences to validate (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns one result per sequence, in order: the sequence length if it is
+/// valid, or the error for its first invalid byte.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{validate_batch, NucleotideError};
+///
+/// let results = validate_batch(&[b"ACGT", b"ACNT"]);
+/// assert_eq!(results, vec![Ok(4), Err(NucleotideError::InvalidBase(b'N'))]);
+/// ```
+pub fn validate_batch(seqs: &[&[u8]]) -> Vec<Result<usize, NucleotideError>> {
+    seqs.iter()
+        .map(|seq| {
+            for &base in seq.iter() {
+                base_to_2bit(base)?;
+            }
+            Ok(seq.len())
+        })
+        .collect()
+}
+
+/// A summary of the results of [`validate_batch`].
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{validate_batch, BatchReport};
+///
+/// let results = validate_batch(&[b"ACGT", b"ACNT", b"NNNN"]);
+/// let report = BatchReport::new(&results);
+/// assert_eq!((report.passed(), report.failed()), (1, 2));
+/// assert_eq!(report.error_kinds().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchReport {
+    passed: usize,
+    failed: usize,
+    /// The first error seen of each kind, in order of appearance
+    error_kinds: Vec<NucleotideError>,
+}
+
+impl BatchReport {
+    /// Summarizes a set of per-sequence validation results.
+    pub fn new(results: &[Result<usize, NucleotideError>]) -> Self {
+        let mut report = Self::default();
+        for result in results {
+            match result {
+                Ok(_) => report.passed += 1,
+                Err(err) => {
+                    report.failed += 1;
+                    let kind = std::mem::discriminant(err);
+                    if !report
+                        .error_kinds
+                        .iter()
+                        .any(|seen| std::mem::discriminant(seen) == kind)
+                    {
+                        report.error_kinds.push(err.clone());
+                    }
+                }
+            }
+        }
+        report
+    }
+
+    /// The number of sequences that passed validation
+    pub fn passed(&self) -> usize {
+        self.passed
+    }
+
+    /// The number of sequences that failed validation
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// The first error of each distinct kind, in order of appearance
+    pub fn error_kinds(&self) -> &[NucleotideError] {
+        &self.error_kinds
+    }
+}
+
+/// Checks that the bits of a 2-bit packed sequence beyond `len` bases are zero.
+///
+/// Packing functions always leave the unused high bits clear, so stray bits in
+/// a deserialized value point to corruption or to mismatched length metadata.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The number of bases in the packed sequence
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, is_well_formed_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACGT")?;
+/// assert!(is_well_formed_2bit(packed, 4)?);
+/// assert!(!is_well_formed_2bit(packed, 3)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_well_formed_2bit(packed: u64, len: usize) -> Result<bool, NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    Ok(packed & !window_mask(len) == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_validate_reader() {
+        let mut stream = Vec::new();
+        for _ in 0..10_000 {
+            stream.extend_from_slice(b"ACGTACGTAC\n");
+        }
+        let n_bases = validate_reader(&mut Cursor::new(&stream), true).unwrap();
+        assert_eq!(n_bases, 100_000);
+    }
+
+    #[test]
+    fn test_validate_reader_invalid_base_deep() {
+        // Place the invalid base past the first read chunk
+        let mut stream = vec![b'A'; CHUNK_SIZE * 2];
+        stream[CHUNK_SIZE + 17] = b'N';
+
+        let err = validate_reader(&mut Cursor::new(&stream), true).unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidBase {
+                offset,
+                base: b'N'
+            } if offset == CHUNK_SIZE + 17
+        ));
+    }
+
+    #[test]
+    fn test_validate_reader_whitespace_toggle() {
+        let stream = b"ACGT ACGT\r\nAC\tGT\n";
+        assert_eq!(validate_reader(&mut Cursor::new(stream), true).unwrap(), 12);
+
+        let err = validate_reader(&mut Cursor::new(stream), false).unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidBase {
+                offset: 4,
+                base: b' '
+            }
+        ));
+    }
+
+    #[test]
+    fn test_validate_reader_empty() {
+        let n_bases = validate_reader(&mut Cursor::new(b""), false).unwrap();
+        assert_eq!(n_bases, 0);
+    }
+
+    #[test]
+    fn test_all_invalid_positions() {
+        let mut seq = b"ACGTACGTACGTACGTACGTACGTACGTACGTacgtacgtacgtacgtacgt".to_vec();
+        seq[0] = b'N';
+        seq[31] = b'X';
+        seq[33] = 0xFF;
+        seq[51] = b'-';
+
+        let invalid = all_invalid_positions(&seq);
+        assert_eq!(invalid, vec![(0, b'N'), (31, b'X'), (33, 0xFF), (51, b'-')]);
+    }
+
+    #[test]
+    fn test_all_invalid_positions_none() {
+        let seq = b"ACGTACGTACGTACGTACGTACGTACGTACGTacgtacgtacgtacgtacgt";
+        assert!(all_invalid_positions(seq).is_empty());
+        assert!(all_invalid_positions(b"").is_empty());
+    }
+
+    #[test]
+    fn test_all_invalid_positions_case_neighbors() {
+        // Bytes that differ from ACGT by a bit other than the case bit
+        let seq = [b'A' | 0x80, b'C' ^ 0x01, b'g' ^ 0x40, b'T'];
+        let invalid = all_invalid_positions(&seq);
+        assert_eq!(invalid.len(), 3);
+    }
+
+    #[test]
+    fn test_validate_batch_report() {
+        let seqs: [&[u8]; 5] = [b"ACGT", b"acgtn", b"", b"AC\xffT", b"GGXAT"];
+        let results = validate_batch(&seqs);
+        assert_eq!(
+            results,
+            vec![
+                Ok(4),
+                Err(NucleotideError::InvalidBase(b'n')),
+                Ok(0),
+                Err(NucleotideError::NonAscii(0xff)),
+                Err(NucleotideError::InvalidBase(b'X')),
+            ]
+        );
+
+        let report = BatchReport::new(&results);
+        assert_eq!(report.passed(), 2);
+        assert_eq!(report.failed(), 3);
+        assert_eq!(
+            report.error_kinds(),
+            &[
+                NucleotideError::InvalidBase(b'n'),
+                NucleotideError::NonAscii(0xff)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_report_empty() {
+        let report = BatchReport::new(&validate_batch(&[]));
+        assert_eq!(report, BatchReport::default());
+    }
+
+    #[test]
+    fn test_is_well_formed_2bit() {
+        let packed = crate::as_2bit(b"GATTACA").unwrap();
+        assert_eq!(is_well_formed_2bit(packed, 7), Ok(true));
+        assert_eq!(is_well_formed_2bit(packed | (1 << 14), 7), Ok(false));
+        assert_eq!(is_well_formed_2bit(packed | (1 << 63), 7), Ok(false));
+
+        assert_eq!(is_well_formed_2bit(u64::MAX, 32), Ok(true));
+        assert_eq!(is_well_formed_2bit(0, 0), Ok(true));
+        assert_eq!(is_well_formed_2bit(1, 0), Ok(false));
+        assert_eq!(
+            is_well_formed_2bit(0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+/// Mask of the lower bit of each 2-bit group.
+pub(crate) const LOWER_BITS: u64 = 0x5555555555555555;
+
+/// Returns a mask covering the bits of the first `len` bases of a 2-bit packed u64.
+///
+/// Shifting a u64 by 64 overflows, so the full window (`len >= 32`) is handled
+/// explicitly instead of computing `(1 << (len * 2)) - 1`.
+#[inline(always)]
+pub(crate) fn window_mask(len: usize) -> u64 {
+    if len >= 32 {
+        u64::MAX
+    } else {
+        (1u64 << (len * 2)) - 1
+    }
+}
+
+/// Expands a per-base position mask into a mask over the 2-bit groups of those bases.
+///
+/// Bit `i` of `mask` sets both bits of 2-bit group `i`.
+#[inline]
+pub(crate) fn expand_base_mask(mask: u32) -> u64 {
+    // Spread each bit onto the lower bit of its 2-bit group
+    let mut x = mask as u64;
+    x = (x | (x << 16)) & 0x0000FFFF0000FFFF;
+    x = (x | (x << 8)) & 0x00FF00FF00FF00FF;
+    x = (x | (x << 4)) & 0x0F0F0F0F0F0F0F0F;
+    x = (x | (x << 2)) & 0x3333333333333333;
+    x = (x | (x << 1)) & LOWER_BITS;
+    x | (x << 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_mask() {
+        assert_eq!(window_mask(0), 0);
+        assert_eq!(window_mask(1), 0b11);
+        assert_eq!(window_mask(4), 0xFF);
+        assert_eq!(window_mask(31), u64::MAX >> 2);
+        assert_eq!(window_mask(32), u64::MAX);
+    }
+
+    #[test]
+    fn test_expand_base_mask() {
+        assert_eq!(expand_base_mask(0), 0);
+        assert_eq!(expand_base_mask(u32::MAX), u64::MAX);
+        assert_eq!(expand_base_mask(0b101), 0b110011);
+        assert_eq!(expand_base_mask(1 << 31), 0b11 << 62);
+    }
+
+    #[test]
+    fn test_window_edges_across_apis() {
+        use crate::{
+            as_2bit, canonical_key_2bit, gc_count_2bit, hamming1_neighbors, hdist_scalar,
+            interleave_2bit, is_reverse_complement_2bit,
+        };
+
+        // Runs in debug builds, so any shift overflow would panic
+        for len in [31, 32] {
+            let seq = &b"GCGCGCGCGCGCGCGCGCGCGCGCGCGCGCGC"[..len];
+            let packed = as_2bit(seq).unwrap() | !window_mask(len);
+
+            assert_eq!(gc_count_2bit(packed, len), Ok(len as u32));
+            assert_eq!(hdist_scalar(packed, packed & window_mask(len), len), Ok(0));
+            assert_eq!(hamming1_neighbors(packed, len).unwrap().len(), 3 * len);
+
+            let (canonical, _) = canonical_key_2bit(packed, len).unwrap();
+            assert_eq!(canonical & !window_mask(len), 0);
+            assert_eq!(is_reverse_complement_2bit(packed, canonical, len), Ok(true));
+        }
+        assert!(interleave_2bit(u64::MAX, u64::MAX, 16).is_ok());
+    }
+}
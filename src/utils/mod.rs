@@ -1,11 +1,44 @@
 pub mod analysis;
 pub mod functions;
+mod mask;
 pub mod packing;
 pub mod unpacking;
 
-pub use functions::{hdist, hdist_scalar, split_packed};
-pub use packing::{as_2bit, encode_internal};
-pub use unpacking::{from_2bit, from_2bit_alloc, from_2bit_multi};
+pub use functions::{
+    all_invalid_positions, as_2bit_both_strands, base_at_2bit, blocks_eq, canonical_bases,
+    canonical_fingerprint_blocks, canonical_key_2bit, canonical_kmers, cmp_2bit, compression_ratio,
+    concat_2bit, consensus_base, contains_kmer_blocks, count_base_blocks, deinterleave_2bit,
+    diff_bases_2bit, eq_masked_2bit, find_palindromes, fingerprint, from_bits_be, from_rle_2bit,
+    fuse_kmers, gc_count_2bit, gc_count_blocks, gc_fraction_2bit, gc_fraction_2bit_strict,
+    hamming1_neighbors, hamming_against_many, hamming_blocks, hash_canonical, hash_kmer, hdist,
+    hdist_scalar, interleave_2bit, is_homopolymer_2bit, is_palindrome_2bit,
+    is_reverse_complement_2bit, is_well_formed_2bit, jaccard_blocks, kmer_at_blocks,
+    kmer_complexity, kmer_cosine, levenshtein_banded_2bit, longest_common_substring_2bit,
+    lsh_bucket, min_rotation_2bit, minhash_similarity, minhash_sketch, nearest_2bit,
+    normalize_nucleotides, one_hot_2bit, pack_column, packed_size_bytes, poly_tail_len_2bit,
+    position_frequency_matrix, positional_entropy, predecessors_2bit, prefix_2bit,
+    present_bases_2bit, rank_base_2bit, repeat_period_2bit, revcomp_bases, reverse_complement_2bit,
+    reverse_complement_2bit_alphabet, reverse_complement_blocks, rolling_gc, rotate_left_2bit,
+    rotate_right_2bit, run_length_histogram_2bit, sliding_gc, split_fused, split_packed,
+    successors_2bit, suffix_2bit, tiles_2bit, to_bits_be, to_rle_2bit, trim_by_mask_2bit,
+    ts_tv_blocks, ungapped_score_2bit, validate_batch, validate_reader, BarcodeIndex, BatchReport,
+    KmerHash, KmerHasher, PackedReader,
+};
+pub use packing::{
+    active_backend, as_2bit, as_2bit_batch_uniform, as_2bit_bitsliced, as_2bit_bytes,
+    as_2bit_fixed, as_2bit_ignore_whitespace, as_2bit_iupac_lossy, as_2bit_lossy_with,
+    as_2bit_nucleic, as_2bit_one_ambiguous, as_2bit_reversed, as_2bit_split, as_2bit_u128,
+    as_2bit_with_alphabet, as_2bit_with_backend, as_2bit_with_coords, as_2bit_with_sentinel,
+    base_at_with_sentinel, build_lookup, complement_base, encode_internal, expand_iupac,
+    merge_lossy_2bit, pack_fasta_sequence, pack_fastq_record, Backend, PackExt, PackedMap,
+    ASCII_TO_CODE, COMPLEMENT_CODE, INVALID_CODE, SENTINEL,
+};
+pub use unpacking::{
+    blocks_to_fasta_string, decode_records, from_2bit, from_2bit_alloc, from_2bit_bitsliced,
+    from_2bit_bytes, from_2bit_multi, from_2bit_padded, from_2bit_u128, from_2bit_with_alphabet,
+};
+
+pub(crate) use mask::{expand_base_mask, window_mask, LOWER_BITS};
 
 use crate::NucleotideError;
 
@@ -1,5 +1,7 @@
+use super::lookup::{ASCII_TO_CODE, INVALID_CODE};
 use super::naive;
 use crate::error::NucleotideError;
+use crate::MAX_BASES;
 use std::arch::aarch64::*;
 
 /// Represents the 2-bit encoding for each nucleotide
@@ -74,7 +76,7 @@ unsafe fn process_simd_chunk(chunk: uint8x8_t, constants: &SimdConstants) -> uin
 #[cfg(target_arch = "aarch64")]
 #[inline(always)]
 pub fn as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
-    if seq.len() > 32 {
+    if seq.len() > MAX_BASES {
         return Err(NucleotideError::SequenceTooLong(seq.len()));
     }
 
@@ -85,9 +87,9 @@ pub fn as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
     // Pre-validate all bases using SIMD when possible
     if let Some(&invalid) = seq
         .iter()
-        .find(|&&b| !matches!(b, b'A' | b'a' | b'C' | b'c' | b'G' | b'g' | b'T' | b't'))
+        .find(|&&b| ASCII_TO_CODE[b as usize] == INVALID_CODE)
     {
-        return Err(NucleotideError::InvalidBase(invalid));
+        return Err(NucleotideError::invalid_byte(invalid));
     }
 
     let mut packed = 0u64;
@@ -113,13 +115,7 @@ pub fn as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
 
         // Handle remaining nucleotides
         for (i, &base) in seq.iter().skip(simd_len).enumerate() {
-            let bits = match base {
-                b'A' | b'a' => NucleotideBits::A as u64,
-                b'C' | b'c' => NucleotideBits::C as u64,
-                b'G' | b'g' => NucleotideBits::G as u64,
-                b'T' | b't' => NucleotideBits::T as u64,
-                _ => unreachable!(),
-            };
+            let bits = ASCII_TO_CODE[base as usize] as u64;
             packed |= bits << ((simd_len + i) * 2);
         }
     }
@@ -192,7 +188,13 @@ pub unsafe fn encode_nucleotides_simd(
         let v0 = vld1q_u8(ip);
         let v1 = vld1q_u8(ip.add(16));
         if !valid_block(v0) || !valid_block(v1) {
-            return Err(NucleotideError::InvalidBase(*ip));
+            // Fall back to a scalar scan to report the offending byte
+            let block = std::slice::from_raw_parts(ip, 32);
+            let invalid = block
+                .iter()
+                .find(|&&b| ASCII_TO_CODE[b as usize] == INVALID_CODE)
+                .map_or(*ip, |&b| b);
+            return Err(NucleotideError::invalid_byte(invalid));
         }
         *out = (encode_16_nucleotides(v0) as u64) | ((encode_16_nucleotides(v1) as u64) << 32);
 
@@ -210,7 +212,7 @@ pub unsafe fn encode_nucleotides_simd(
                 b'c' => 1u64,
                 b'g' => 2u64,
                 b't' => 3u64,
-                _ => return Err(NucleotideError::InvalidBase(*ip.add(i))),
+                _ => return Err(NucleotideError::invalid_byte(*ip.add(i))),
             } << (2 * i);
         }
         *out = tail;
@@ -0,0 +1,93 @@
+use super::as_2bit;
+use crate::NucleotideError;
+
+/// Extends iterators over nucleotide sequences with a packing combinator.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, PackExt};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let seqs = ["ACGT", "GGCC"];
+/// let packed = seqs.iter().packed().collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(packed, vec![as_2bit(b"ACGT")?, as_2bit(b"GGCC")?]);
+/// # Ok(())
+/// # }
+/// ```
+pub trait PackExt: Iterator + Sized
+where
+    Self::Item: AsRef<[u8]>,
+{
+    /// Packs each sequence of the iterator with [`as_2bit`](crate::as_2bit).
+    fn packed(self) -> PackedMap<Self> {
+        PackedMap { iter: self }
+    }
+}
+
+impl<I> PackExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+}
+
+/// An iterator that packs each sequence of an underlying iterator.
+///
+/// Created by [`PackExt::packed`]. Each item is the result of
+/// [`as_2bit`](crate::as_2bit) on the corresponding sequence, so a sequence that
+/// fails to pack does not stop the iteration.
+#[derive(Debug, Clone)]
+pub struct PackedMap<I> {
+    iter: I,
+}
+
+impl<I> Iterator for PackedMap<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = Result<u64, NucleotideError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|seq| as_2bit(seq.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packed_collect() {
+        let seqs: Vec<&[u8]> = vec![b"ACGT", b"TTTT", b"GA"];
+        let packed = seqs.into_iter().packed().collect::<Result<Vec<_>, _>>();
+        assert_eq!(
+            packed,
+            Ok(vec![
+                as_2bit(b"ACGT").unwrap(),
+                as_2bit(b"TTTT").unwrap(),
+                as_2bit(b"GA").unwrap()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_packed_errors_per_item() {
+        let seqs: Vec<Vec<u8>> = vec![b"AC".to_vec(), b"AN".to_vec(), vec![b'A'; 33]];
+        let packed: Vec<_> = seqs.iter().packed().collect();
+        assert_eq!(
+            packed,
+            vec![
+                Ok(as_2bit(b"AC").unwrap()),
+                Err(NucleotideError::InvalidBase(b'N')),
+                Err(NucleotideError::SequenceTooLong(33)),
+            ]
+        );
+        assert_eq!(seqs.iter().packed().size_hint(), (3, Some(3)));
+    }
+}
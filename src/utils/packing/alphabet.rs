@@ -0,0 +1,114 @@
+use super::lookup::{build_lookup, INVALID_CODE};
+use crate::{NucleotideError, MAX_BASES};
+
+/// Checks that an alphabet assigns each of A, C, G, T to exactly one code.
+pub(crate) fn validate_alphabet(alphabet: [u8; 4]) -> Result<(), NucleotideError> {
+    let mut seen = [false; 4];
+    for base in alphabet {
+        let idx = match base {
+            b'A' => 0,
+            b'C' => 1,
+            b'G' => 2,
+            b'T' => 3,
+            _ => return Err(NucleotideError::InvalidAlphabet(alphabet)),
+        };
+        if seen[idx] {
+            return Err(NucleotideError::InvalidAlphabet(alphabet));
+        }
+        seen[idx] = true;
+    }
+    Ok(())
+}
+
+/// Converts a nucleotide sequence into a 2-bit packed representation using a custom code order.
+///
+/// `alphabet[code]` is the base assigned to each 2-bit code, so the standard
+/// encoding used by [`as_2bit`](crate::as_2bit) corresponds to `*b"ACGT"`.
+/// Bases are packed from least significant to most significant bits as usual.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `alphabet` - The uppercase base assigned to codes 0 through 3
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidAlphabet` if `alphabet` is not a permutation of A, C, G, T.
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// other than A,C,G,T (case insensitive).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::as_2bit_with_alphabet;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // A=0, C=1, T=2, G=3
+/// let packed = as_2bit_with_alphabet(b"ACGT", *b"ACTG")?;
+/// assert_eq!(packed, 0b10110100);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_with_alphabet(seq: &[u8], alphabet: [u8; 4]) -> Result<u64, NucleotideError> {
+    validate_alphabet(alphabet)?;
+    if seq.len() > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    let lookup = build_lookup(alphabet);
+    let mut packed = 0u64;
+    for (i, &base) in seq.iter().enumerate() {
+        let bits = match lookup[base as usize] {
+            INVALID_CODE => return Err(NucleotideError::invalid_byte(base)),
+            code => code as u64,
+        };
+        packed |= bits << (i * 2);
+    }
+    Ok(packed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_standard_alphabet_matches_as_2bit() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAA";
+        assert_eq!(
+            as_2bit_with_alphabet(seq, *b"ACGT"),
+            Ok(as_2bit(seq).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_permuted_alphabet() {
+        assert_eq!(as_2bit_with_alphabet(b"ACTG", *b"ACTG"), Ok(0b11100100));
+        assert_eq!(as_2bit_with_alphabet(b"tgca", *b"TGCA"), Ok(0b11100100));
+    }
+
+    #[test]
+    fn test_invalid_alphabet() {
+        for alphabet in [*b"ACGA", *b"ACGN", *b"acgt"] {
+            assert_eq!(
+                as_2bit_with_alphabet(b"ACGT", alphabet),
+                Err(NucleotideError::InvalidAlphabet(alphabet))
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_sequence() {
+        assert_eq!(
+            as_2bit_with_alphabet(b"ACGN", *b"TGCA"),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        assert_eq!(
+            as_2bit_with_alphabet(&[b'A'; 33], *b"TGCA"),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+}
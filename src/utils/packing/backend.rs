@@ -0,0 +1,143 @@
+#[cfg(all(target_arch = "aarch64", not(feature = "nosimd")))]
+use super::aarch64;
+#[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+use super::{avx, sse};
+
+use super::naive;
+use crate::NucleotideError;
+
+/// The implementations available for packing nucleotides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// Portable scalar implementation
+    Naive,
+    /// 128 bit x86_64 instructions
+    Sse2,
+    /// 256 bit x86_64 instructions
+    Avx2,
+    /// 128 bit aarch64 instructions
+    Neon,
+}
+
+impl Backend {
+    /// Whether this backend can be used on the current machine.
+    ///
+    /// SIMD backends are never available when the `nosimd` feature is enabled
+    /// or when compiling for a different architecture.
+    pub fn is_available(self) -> bool {
+        match self {
+            Backend::Naive => true,
+            #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+            Backend::Avx2 => is_x86_feature_detected!("avx2"),
+            #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+            Backend::Sse2 => is_x86_feature_detected!("sse2"),
+            #[cfg(all(target_arch = "aarch64", not(feature = "nosimd")))]
+            Backend::Neon => std::arch::is_aarch64_feature_detected!("neon"),
+            _ => false,
+        }
+    }
+}
+
+/// Returns the backend that [`as_2bit`](crate::as_2bit) dispatches to on this machine.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::active_backend;
+///
+/// let backend = active_backend();
+/// assert!(backend.is_available());
+/// ```
+pub fn active_backend() -> Backend {
+    [Backend::Avx2, Backend::Sse2, Backend::Neon]
+        .into_iter()
+        .find(|backend| backend.is_available())
+        .unwrap_or(Backend::Naive)
+}
+
+/// Converts a nucleotide sequence into a 2-bit packed representation using a specific backend.
+///
+/// This behaves exactly like [`as_2bit`](crate::as_2bit), but bypasses runtime
+/// detection so that individual implementations can be compared without
+/// recompiling.
+///
+/// Note that if the requested backend is not available at runtime (see
+/// [`Backend::is_available`]) the naive implementation is used instead.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+/// * `backend` - The implementation to pack with
+///
+/// # Errors
+///
+/// Returns the same errors as [`as_2bit`](crate::as_2bit).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_with_backend, Backend};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let naive = as_2bit_with_backend(b"ACGT", Backend::Naive)?;
+/// assert_eq!(naive, as_2bit(b"ACGT")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_with_backend(seq: &[u8], backend: Backend) -> Result<u64, NucleotideError> {
+    match backend {
+        #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+        Backend::Avx2 if backend.is_available() => avx::as_2bit(seq),
+        #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
+        Backend::Sse2 if backend.is_available() => sse::as_2bit(seq),
+        #[cfg(all(target_arch = "aarch64", not(feature = "nosimd")))]
+        Backend::Neon if backend.is_available() => aarch64::as_2bit(seq),
+        _ => naive::as_2bit(seq),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BACKENDS: [Backend; 4] = [Backend::Naive, Backend::Sse2, Backend::Avx2, Backend::Neon];
+
+    #[test]
+    fn test_backends_agree() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAA";
+        for len in 0..=seq.len() {
+            let expected = naive::as_2bit(&seq[..len]).unwrap();
+            for backend in BACKENDS {
+                assert_eq!(
+                    as_2bit_with_backend(&seq[..len], backend),
+                    Ok(expected),
+                    "Failed for {:?} at length {}",
+                    backend,
+                    len
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_backends_errors() {
+        for backend in BACKENDS {
+            assert_eq!(
+                as_2bit_with_backend(b"ACGN", backend),
+                Err(NucleotideError::InvalidBase(b'N'))
+            );
+            assert_eq!(
+                as_2bit_with_backend(&[b'A'; 33], backend),
+                Err(NucleotideError::SequenceTooLong(33))
+            );
+        }
+    }
+
+    #[test]
+    fn test_active_backend() {
+        assert!(active_backend().is_available());
+        assert!(Backend::Naive.is_available());
+        #[cfg(feature = "nosimd")]
+        assert_eq!(active_backend(), Backend::Naive);
+    }
+}
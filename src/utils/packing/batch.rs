@@ -0,0 +1,108 @@
+use super::as_2bit;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Packs a contiguous buffer of fixed-length records into 2-bit packed values.
+///
+/// `seqs` is interpreted as `seqs.len() / seq_len` consecutive records of `seq_len`
+/// bases each, and record `i` is packed into `out[i]`.
+/// Keeping records contiguous avoids the pointer chasing of a `Vec<&[u8]>`.
+///
+/// # Arguments
+///
+/// * `seqs` - The concatenated records
+/// * `seq_len` - The length of each record in bases (1..=32)
+/// * `out` - The buffer to write the packed records to, at least one slot per record
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if `seq_len` is greater than 32.
+///
+/// Returns `NucleotideError::InvalidLength` if `seq_len` is 0, if `seqs.len()` is
+/// not a multiple of `seq_len`, or if `out` has fewer slots than there are records.
+///
+/// Returns `NucleotideError::InvalidBase` if any record contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_batch_uniform};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut out = [0u64; 3];
+/// as_2bit_batch_uniform(b"ACGTTTTTGGCA", 4, &mut out)?;
+/// assert_eq!(out, [as_2bit(b"ACGT")?, as_2bit(b"TTTT")?, as_2bit(b"GGCA")?]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_batch_uniform(
+    seqs: &[u8],
+    seq_len: usize,
+    out: &mut [u64],
+) -> Result<(), NucleotideError> {
+    if seq_len > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(seq_len));
+    }
+    if seq_len == 0 {
+        return Err(NucleotideError::InvalidLength(seq_len));
+    }
+    if !seqs.len().is_multiple_of(seq_len) {
+        return Err(NucleotideError::InvalidLength(seqs.len()));
+    }
+    let n_records = seqs.len() / seq_len;
+    if out.len() < n_records {
+        return Err(NucleotideError::InvalidLength(out.len()));
+    }
+
+    for (slot, record) in out.iter_mut().zip(seqs.chunks_exact(seq_len)) {
+        *slot = as_2bit(record)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nucgen::Sequence;
+
+    #[test]
+    fn test_batch_uniform() {
+        let n_records = 1000;
+        let seq_len = 20;
+
+        let mut rng = rand::thread_rng();
+        let mut seq = Sequence::new();
+        seq.fill_buffer(&mut rng, n_records * seq_len);
+
+        let mut out = vec![0u64; n_records];
+        as_2bit_batch_uniform(seq.bytes(), seq_len, &mut out).unwrap();
+
+        for (packed, record) in out.iter().zip(seq.bytes().chunks_exact(seq_len)) {
+            assert_eq!(*packed, as_2bit(record).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_batch_uniform_validation() {
+        let mut out = vec![0u64; 2];
+        assert_eq!(
+            as_2bit_batch_uniform(b"ACGTA", 2, &mut out),
+            Err(NucleotideError::InvalidLength(5))
+        );
+        assert_eq!(
+            as_2bit_batch_uniform(b"ACGTAC", 2, &mut out),
+            Err(NucleotideError::InvalidLength(2))
+        );
+        assert_eq!(
+            as_2bit_batch_uniform(b"ACGT", 0, &mut out),
+            Err(NucleotideError::InvalidLength(0))
+        );
+        assert_eq!(
+            as_2bit_batch_uniform(&[b'A'; 66], 33, &mut out),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+        assert_eq!(
+            as_2bit_batch_uniform(b"ACGN", 2, &mut out),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+}
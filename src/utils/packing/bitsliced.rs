@@ -0,0 +1,70 @@
+use super::as_2bit;
+use crate::NucleotideError;
+
+/// Converts a nucleotide sequence of up to 32 bases into a bit-sliced layout.
+///
+/// Instead of keeping each base's two bits together, the low bits of every base
+/// are gathered into one u32 and the high bits into another, so bit `i` of each
+/// word belongs to base `i`. Position-wise operations across sequences then
+/// become plain bitwise operations on the two words.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns `(low_bits, high_bits, len)`.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// other than A,C,G,T (case insensitive).
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
+/// than 32 bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::as_2bit_bitsliced;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // A=00, C=01, G=10, T=11
+/// let (low, high, len) = as_2bit_bitsliced(b"ACGT")?;
+/// assert_eq!(low, 0b1010);
+/// assert_eq!(high, 0b1100);
+/// assert_eq!(len, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_bitsliced(seq: &[u8]) -> Result<(u32, u32, usize), NucleotideError> {
+    let packed = as_2bit(seq)?;
+
+    let mut low = 0u32;
+    let mut high = 0u32;
+    for i in 0..seq.len() {
+        low |= (((packed >> (i * 2)) & 1) as u32) << i;
+        high |= (((packed >> (i * 2 + 1)) & 1) as u32) << i;
+    }
+    Ok((low, high, seq.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_2bit_bitsliced() {
+        let (low, high, len) = as_2bit_bitsliced(&[b'T'; 32]).unwrap();
+        assert_eq!((low, high, len), (u32::MAX, u32::MAX, 32));
+
+        let (low, high, len) = as_2bit_bitsliced(b"GGCC").unwrap();
+        assert_eq!((low, high, len), (0b1100, 0b0011, 4));
+
+        assert_eq!(
+            as_2bit_bitsliced(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use super::base_to_2bit;
+use crate::NucleotideError;
+
+/// Packs a nucleotide sequence into bytes, four bases per byte.
+///
+/// This follows the UCSC `.2bit` convention rather than the LSB-first layout
+/// used by [`as_2bit`](crate::as_2bit): base `i` is stored in byte `i / 4`,
+/// and within a byte the first base occupies the two most-significant bits
+/// (bits 7-6), followed by bits 5-4, 3-2 and 1-0.
+/// Unused bits of the last byte are zero.
+///
+/// Sequences of any length are accepted.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns `seq.len().div_ceil(4)` packed bytes.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains non-ACGT characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::as_2bit_bytes;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(as_2bit_bytes(b"ACGTG")?, vec![0b00_01_10_11, 0b10_00_00_00]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_bytes(seq: &[u8]) -> Result<Vec<u8>, NucleotideError> {
+    seq.chunks(4)
+        .map(|chunk| {
+            chunk.iter().enumerate().try_fold(0u8, |byte, (i, &base)| {
+                let code = base_to_2bit(base)? as u8;
+                Ok(byte | (code << (6 - 2 * i)))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_2bit_bytes_layout() {
+        assert_eq!(as_2bit_bytes(b"TAAA").unwrap(), vec![0b1100_0000]);
+        assert_eq!(as_2bit_bytes(b"AAAT").unwrap(), vec![0b0000_0011]);
+        assert_eq!(as_2bit_bytes(b"gc").unwrap(), vec![0b1001_0000]);
+        assert_eq!(as_2bit_bytes(b"TTTTT").unwrap(), vec![0xFF, 0b1100_0000]);
+        assert!(as_2bit_bytes(b"").unwrap().is_empty());
+        assert_eq!(
+            as_2bit_bytes(b"ACGTN"),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use super::as_2bit;
+use crate::NucleotideError;
+
+/// Packs each delimiter-separated segment of a sequence into its own 2-bit value.
+///
+/// Segments are split exactly like [`slice::split`], so consecutive delimiters
+/// (or a leading or trailing delimiter) produce empty segments, which are
+/// returned as `(0, 0)`.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice of ASCII nucleotides (A,C,G,T, case insensitive) separated by `delimiter`
+/// * `delimiter` - The byte separating segments
+///
+/// # Returns
+///
+/// Returns the packed value and base count of each segment, in input order.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if any segment contains characters
+/// other than A,C,G,T (case insensitive).
+///
+/// Returns `NucleotideError::SequenceTooLong` if any segment is longer than 32 bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_split};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let segments = as_2bit_split(b"ACGT|GGC", b'|')?;
+/// assert_eq!(segments, vec![(as_2bit(b"ACGT")?, 4), (as_2bit(b"GGC")?, 3)]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_split(seq: &[u8], delimiter: u8) -> Result<Vec<(u64, usize)>, NucleotideError> {
+    seq.split(|&b| b == delimiter)
+        .map(|segment| Ok((as_2bit(segment)?, segment.len())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_2bit_split() {
+        let segments = as_2bit_split(b"ACGT|GGCC", b'|').unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                (as_2bit(b"ACGT").unwrap(), 4),
+                (as_2bit(b"GGCC").unwrap(), 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_as_2bit_split_empty_segments() {
+        let segments = as_2bit_split(b"|AC||T|", b'|').unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                (0, 0),
+                (as_2bit(b"AC").unwrap(), 2),
+                (0, 0),
+                (as_2bit(b"T").unwrap(), 1),
+                (0, 0)
+            ]
+        );
+        assert_eq!(as_2bit_split(b"", b'|'), Ok(vec![(0, 0)]));
+    }
+
+    #[test]
+    fn test_as_2bit_split_errors() {
+        assert_eq!(
+            as_2bit_split(b"ACGT|GNCC", b'|'),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+
+        let mut seq = b"ACGT,".to_vec();
+        seq.extend_from_slice(&[b'A'; 33]);
+        assert_eq!(
+            as_2bit_split(&seq, b','),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use super::as_2bit;
+use crate::NucleotideError;
+
+/// Converts a nucleotide sequence into a fixed-size array of 2-bit packed blocks.
+///
+/// This is the stack allocated counterpart to [`encode`](crate::encode) for sequences
+/// with a known upper bound on their length.
+/// Each block holds 32 bases, blocks past the end of the sequence are zeroed.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns the block array and the number of bases packed.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the sequence is longer than `N * 32` bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// other than A,C,G,T (case insensitive).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_fixed};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (blocks, n_bases) = as_2bit_fixed::<4>(b"ACGT")?;
+/// assert_eq!(blocks, [as_2bit(b"ACGT")?, 0, 0, 0]);
+/// assert_eq!(n_bases, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_fixed<const N: usize>(seq: &[u8]) -> Result<([u64; N], usize), NucleotideError> {
+    if seq.len() > N * 32 {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    let mut blocks = [0u64; N];
+    for (block, chunk) in blocks.iter_mut().zip(seq.chunks(32)) {
+        *block = as_2bit(chunk)?;
+    }
+    Ok((blocks, seq.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode_alloc;
+
+    #[test]
+    fn test_as_2bit_fixed() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCT"; // 40 bases
+        let (blocks, n_bases) = as_2bit_fixed::<2>(seq).unwrap();
+        assert_eq!(n_bases, 40);
+        assert_eq!(blocks.to_vec(), encode_alloc(seq).unwrap());
+    }
+
+    #[test]
+    fn test_as_2bit_fixed_zeroed_tail() {
+        let (blocks, n_bases) = as_2bit_fixed::<3>(b"").unwrap();
+        assert_eq!(blocks, [0, 0, 0]);
+        assert_eq!(n_bases, 0);
+    }
+
+    #[test]
+    fn test_as_2bit_fixed_errors() {
+        assert_eq!(
+            as_2bit_fixed::<1>(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+        assert_eq!(
+            as_2bit_fixed::<2>(b"ACGN"),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{NucleotideError, MAX_BASES};
+
+/// Maximum number of concrete sequences [`expand_iupac`] will produce
+const MAX_EXPANSION: usize = 4096;
+
+/// Returns the 2-bit codes an IUPAC nucleotide code can resolve to (case insensitive)
+fn iupac_codes(base: u8) -> Result<&'static [u64], NucleotideError> {
+    let codes: &'static [u64] = match base.to_ascii_uppercase() {
+        b'A' => &[0],
+        b'C' => &[1],
+        b'G' => &[2],
+        b'T' => &[3],
+        b'R' => &[0, 2],
+        b'Y' => &[1, 3],
+        b'S' => &[1, 2],
+        b'W' => &[0, 3],
+        b'K' => &[2, 3],
+        b'M' => &[0, 1],
+        b'B' => &[1, 2, 3],
+        b'D' => &[0, 2, 3],
+        b'H' => &[0, 1, 3],
+        b'V' => &[0, 1, 2],
+        b'N' => &[0, 1, 2, 3],
+        _ => return Err(NucleotideError::invalid_byte(base)),
+    };
+    Ok(codes)
+}
+
+/// Expands a degenerate nucleotide sequence into every concrete sequence it matches.
+///
+/// Each IUPAC ambiguity code (R, Y, S, W, K, M, B, D, H, V, N) is resolved to
+/// each of the bases it stands for, and every resolution is packed as
+/// [`as_2bit`](crate::as_2bit) would pack it. Resolutions are ordered
+/// lexicographically by base (A < C < G < T), with the last position varying
+/// fastest.
+///
+/// At most 4096 sequences are produced.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII IUPAC nucleotide codes (case insensitive)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
+/// than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// that are not IUPAC nucleotide codes.
+///
+/// Returns `NucleotideError::ExpansionTooLarge` with the number of resolutions
+/// (saturated at `usize::MAX`) if it is greater than 4096.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, expand_iupac};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let expanded = expand_iupac(b"AR")?;
+/// assert_eq!(expanded, vec![as_2bit(b"AA")?, as_2bit(b"AG")?]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn expand_iupac(seq: &[u8]) -> Result<Vec<u64>, NucleotideError> {
+    if seq.len() > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    let resolved = seq
+        .iter()
+        .map(|&base| iupac_codes(base))
+        .collect::<Result<Vec<_>, _>>()?;
+    let n_expanded = resolved
+        .iter()
+        .fold(1usize, |n, codes| n.saturating_mul(codes.len()));
+    if n_expanded > MAX_EXPANSION {
+        return Err(NucleotideError::ExpansionTooLarge(n_expanded));
+    }
+
+    // Extend every partial resolution by each code of the next position
+    let mut expanded = Vec::with_capacity(n_expanded);
+    expanded.push(0u64);
+    for (i, codes) in resolved.iter().enumerate() {
+        expanded = expanded
+            .iter()
+            .flat_map(|&prefix| codes.iter().map(move |&code| prefix | (code << (i * 2))))
+            .collect();
+    }
+    Ok(expanded)
+}
+
+/// Converts a nucleotide sequence with at most one IUPAC ambiguity code into
+/// every 2-bit packed sequence it matches.
+///
+/// This is a lightweight form of [`expand_iupac`] for the common case of a
+/// single degenerate position, such as a wobble base in a primer. The
+/// resolutions are ordered by the base (A < C < G < T) chosen at the
+/// ambiguous position. A sequence without
+/// ambiguity codes gives a single packed value.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII IUPAC nucleotide codes (case insensitive)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
+/// than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// that are not IUPAC nucleotide codes.
+///
+/// Returns `NucleotideError::TooManyAmbiguities` if the sequence contains more
+/// than one ambiguity code.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_one_ambiguous};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let expanded = as_2bit_one_ambiguous(b"ACY")?;
+/// assert_eq!(expanded, vec![as_2bit(b"ACC")?, as_2bit(b"ACT")?]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_one_ambiguous(seq: &[u8]) -> Result<Vec<u64>, NucleotideError> {
+    if seq.len() > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    let mut packed = 0u64;
+    let mut ambiguous: Option<(usize, &[u64])> = None;
+    for (i, &base) in seq.iter().enumerate() {
+        match iupac_codes(base)? {
+            &[code] => packed |= code << (i * 2),
+            codes if ambiguous.is_none() => ambiguous = Some((i, codes)),
+            _ => return Err(NucleotideError::TooManyAmbiguities),
+        }
+    }
+
+    Ok(match ambiguous {
+        Some((i, codes)) => codes
+            .iter()
+            .map(|&code| packed | (code << (i * 2)))
+            .collect(),
+        None => vec![packed],
+    })
+}
+
+/// Converts a nucleotide sequence into a 2-bit packed u64, resolving each IUPAC
+/// ambiguity code to one of the bases it stands for.
+///
+/// Each ambiguity code is packed as its first member base in A < C < G < T
+/// order (so R and N become A, Y becomes C, and K becomes G) and its position
+/// is recorded in the returned mask. Unlike a fully lossy packing, bytes that
+/// are not IUPAC nucleotide codes are still rejected.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII IUPAC nucleotide codes (case insensitive)
+///
+/// # Returns
+///
+/// Returns the packed value and a mask with bit `i` set if base `i` was ambiguous.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
+/// than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` (or `NucleotideError::NonAscii`) if the
+/// sequence contains any characters that are not IUPAC nucleotide codes.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_iupac_lossy};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (packed, ambiguous) = as_2bit_iupac_lossy(b"ACRT")?;
+/// assert_eq!(packed, as_2bit(b"ACAT")?);
+/// assert_eq!(ambiguous, 0b0100);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_iupac_lossy(seq: &[u8]) -> Result<(u64, u32), NucleotideError> {
+    if seq.len() > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    let mut packed = 0u64;
+    let mut ambiguous = 0u32;
+    for (i, &base) in seq.iter().enumerate() {
+        let codes = iupac_codes(base)?;
+        if codes.len() > 1 {
+            ambiguous |= 1 << i;
+        }
+        packed |= codes[0] << (i * 2);
+    }
+    Ok((packed, ambiguous))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_expand_iupac() {
+        let expanded = expand_iupac(b"ACN").unwrap();
+        let expected: Vec<u64> = [b"ACA", b"ACC", b"ACG", b"ACT"]
+            .iter()
+            .map(|s| as_2bit(*s).unwrap())
+            .collect();
+        assert_eq!(expanded, expected);
+    }
+
+    #[test]
+    fn test_expand_iupac_concrete() {
+        assert_eq!(
+            expand_iupac(b"gattaca"),
+            Ok(vec![as_2bit(b"GATTACA").unwrap()])
+        );
+        assert_eq!(expand_iupac(b""), Ok(vec![0]));
+
+        let expanded = expand_iupac(b"YBs").unwrap();
+        assert_eq!(expanded.len(), 2 * 3 * 2);
+        assert_eq!(expanded[0], as_2bit(b"CCC").unwrap());
+        assert_eq!(expanded[11], as_2bit(b"TTG").unwrap());
+    }
+
+    #[test]
+    fn test_expand_iupac_errors() {
+        assert_eq!(expand_iupac(b"NNNNNN").map(|v| v.len()), Ok(4096));
+        assert_eq!(
+            expand_iupac(b"NNNNNNR"),
+            Err(NucleotideError::ExpansionTooLarge(8192))
+        );
+        assert_eq!(
+            expand_iupac(&[b'N'; 32]),
+            Err(NucleotideError::ExpansionTooLarge(usize::MAX))
+        );
+        assert_eq!(
+            expand_iupac(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+        assert_eq!(
+            expand_iupac(b"ACX"),
+            Err(NucleotideError::InvalidBase(b'X'))
+        );
+    }
+
+    #[test]
+    fn test_as_2bit_one_ambiguous() {
+        let expanded = as_2bit_one_ambiguous(b"ACGR").unwrap();
+        assert_eq!(
+            expanded,
+            vec![as_2bit(b"ACGA").unwrap(), as_2bit(b"ACGG").unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_as_2bit_one_ambiguous_matches_expand() {
+        let seqs: [&[u8]; 4] = [b"GATTACA", b"nAC", b"TTBT", b""];
+        for seq in seqs {
+            assert_eq!(
+                as_2bit_one_ambiguous(seq).unwrap(),
+                expand_iupac(seq).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_as_2bit_one_ambiguous_errors() {
+        assert_eq!(
+            as_2bit_one_ambiguous(b"RACY"),
+            Err(NucleotideError::TooManyAmbiguities)
+        );
+        assert_eq!(
+            as_2bit_one_ambiguous(b"RAXC"),
+            Err(NucleotideError::InvalidBase(b'X'))
+        );
+        assert_eq!(
+            as_2bit_one_ambiguous(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+
+    #[test]
+    fn test_as_2bit_iupac_lossy() {
+        let (packed, ambiguous) = as_2bit_iupac_lossy(b"RYKMSWn").unwrap();
+        assert_eq!(packed, as_2bit(b"ACGACAA").unwrap());
+        assert_eq!(ambiguous, 0b1111111);
+
+        let (packed, ambiguous) = as_2bit_iupac_lossy(b"GAbTvA").unwrap();
+        assert_eq!(packed, as_2bit(b"GACTAA").unwrap());
+        assert_eq!(ambiguous, 0b010100);
+
+        assert_eq!(
+            as_2bit_iupac_lossy(b"acgt"),
+            Ok((as_2bit(b"ACGT").unwrap(), 0))
+        );
+    }
+
+    #[test]
+    fn test_as_2bit_iupac_lossy_errors() {
+        assert_eq!(
+            as_2bit_iupac_lossy(b"ACRX"),
+            Err(NucleotideError::InvalidBase(b'X'))
+        );
+        assert_eq!(
+            as_2bit_iupac_lossy(b"AC-T"),
+            Err(NucleotideError::InvalidBase(b'-'))
+        );
+        assert_eq!(
+            as_2bit_iupac_lossy(b"AC\xe9"),
+            Err(NucleotideError::NonAscii(0xe9))
+        );
+        assert_eq!(
+            as_2bit_iupac_lossy(&[b'N'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+/// Sentinel stored in an ASCII lookup table for bytes that are not nucleotides.
+pub const INVALID_CODE: u8 = 0xFF;
+
+/// Builds an ASCII to 2-bit code lookup table at compile time.
+///
+/// `alphabet[code]` is the uppercase base assigned to each code.
+/// Both the uppercase and lowercase forms of each base map to its code,
+/// and every other byte maps to [`INVALID_CODE`].
+///
+/// The alphabet is expected to be a permutation of A, C, G, T.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{build_lookup, INVALID_CODE};
+///
+/// const LOOKUP: [u8; 256] = build_lookup(*b"ACGT");
+/// assert_eq!(LOOKUP[b'G' as usize], 0b10);
+/// assert_eq!(LOOKUP[b'g' as usize], 0b10);
+/// assert_eq!(LOOKUP[b'N' as usize], INVALID_CODE);
+/// ```
+pub const fn build_lookup(alphabet: [u8; 4]) -> [u8; 256] {
+    let mut table = [INVALID_CODE; 256];
+    let mut code = 0;
+    while code < 4 {
+        let base = alphabet[code];
+        table[base as usize] = code as u8;
+        table[base.to_ascii_lowercase() as usize] = code as u8;
+        code += 1;
+    }
+    table
+}
+
+/// ASCII to 2-bit code lookup table for the standard encoding (A=00, C=01, G=10, T=11).
+///
+/// Both the uppercase and lowercase forms of each base map to its code, and
+/// every other byte maps to [`INVALID_CODE`].
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{ASCII_TO_CODE, INVALID_CODE};
+///
+/// assert_eq!(ASCII_TO_CODE[b't' as usize], 0b11);
+/// assert_eq!(ASCII_TO_CODE[b'N' as usize], INVALID_CODE);
+/// ```
+pub const ASCII_TO_CODE: [u8; 256] = build_lookup(*b"ACGT");
+
+/// The 2-bit code of the complement of each 2-bit code (A<->T, C<->G).
+///
+/// With the standard encoding this is the same as flipping both bits.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{ASCII_TO_CODE, COMPLEMENT_CODE};
+///
+/// let code = ASCII_TO_CODE[b'C' as usize];
+/// assert_eq!(COMPLEMENT_CODE[code as usize], ASCII_TO_CODE[b'G' as usize]);
+/// ```
+pub const COMPLEMENT_CODE: [u8; 4] = [3, 2, 1, 0];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::packing::{base_to_2bit, complement_base};
+
+    #[test]
+    fn test_standard_lookup_matches_encoding() {
+        for byte in 0..=255u8 {
+            let expected = match byte {
+                b'A' | b'a' => 0b00,
+                b'C' | b'c' => 0b01,
+                b'G' | b'g' => 0b10,
+                b'T' | b't' => 0b11,
+                _ => INVALID_CODE,
+            };
+            assert_eq!(ASCII_TO_CODE[byte as usize], expected, "byte {}", byte);
+        }
+    }
+
+    #[test]
+    fn test_permuted_lookup() {
+        let table = build_lookup(*b"TGCA");
+        assert_eq!(table[b'T' as usize], 0);
+        assert_eq!(table[b'g' as usize], 1);
+        assert_eq!(table[b'C' as usize], 2);
+        assert_eq!(table[b'a' as usize], 3);
+        assert_eq!(table[b'U' as usize], INVALID_CODE);
+    }
+
+    #[test]
+    fn test_public_tables_match_encoding() {
+        for byte in 0..=255u8 {
+            match base_to_2bit(byte) {
+                Ok(code) => {
+                    assert_eq!(ASCII_TO_CODE[byte as usize] as u64, code);
+
+                    let complement = base_to_2bit(complement_base(byte).unwrap()).unwrap();
+                    assert_eq!(COMPLEMENT_CODE[code as usize] as u64, complement);
+                }
+                Err(_) => assert_eq!(ASCII_TO_CODE[byte as usize], INVALID_CODE),
+            }
+        }
+        for (code, &complement) in COMPLEMENT_CODE.iter().enumerate() {
+            assert_eq!(complement as usize, !code & 0b11);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use super::naive::base_to_2bit;
+use crate::utils::{expand_base_mask, window_mask};
+use crate::{NucleotideError, MAX_BASES};
+
+/// Converts a nucleotide sequence into a 2-bit packed u64, replacing invalid
+/// characters with a chosen base.
+///
+/// Every byte that is not A,C,G,T (case insensitive) is packed as `substitute`
+/// and its position is recorded in the returned mask, so callers can tell
+/// substituted positions from real bases.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice of ASCII characters
+/// * `substitute` - The nucleotide packed in place of invalid characters (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns the packed value and a mask with bit `i` set if base `i` was substituted.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if `substitute` is not a nucleotide.
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
+/// than 32 bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_lossy_with};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (packed, substituted) = as_2bit_lossy_with(b"ACNT", b'C')?;
+/// assert_eq!(packed, as_2bit(b"ACCT")?);
+/// assert_eq!(substituted, 0b0100);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_lossy_with(seq: &[u8], substitute: u8) -> Result<(u64, u32), NucleotideError> {
+    let substitute_code = base_to_2bit(substitute)?;
+    if seq.len() > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    let mut packed = 0u64;
+    let mut substituted = 0u32;
+    for (i, &base) in seq.iter().enumerate() {
+        let code = base_to_2bit(base).unwrap_or_else(|_| {
+            substituted |= 1 << i;
+            substitute_code
+        });
+        packed |= code << (i * 2);
+    }
+    Ok((packed, substituted))
+}
+
+/// Merges two lossy packs of the same positions, preferring real bases.
+///
+/// At each position the base of `b` is taken only when it is real and the base
+/// of `a` was substituted, otherwise the base of `a` is kept.
+/// Bits beyond `len` bases are cleared in both outputs.
+///
+/// # Arguments
+///
+/// * `a` - A u64 containing the first 2-bit packed sequence
+/// * `a_mask` - The substituted positions of `a`, as returned by [`as_2bit_lossy_with`]
+/// * `b` - A u64 containing the second 2-bit packed sequence
+/// * `b_mask` - The substituted positions of `b`
+/// * `len` - The number of bases in each sequence (<= 32)
+///
+/// # Returns
+///
+/// Returns the merged packed value and a mask of the positions substituted in both inputs.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_lossy_with, merge_lossy_2bit};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (a, a_mask) = as_2bit_lossy_with(b"ACNN", b'A')?;
+/// let (b, b_mask) = as_2bit_lossy_with(b"NCGN", b'A')?;
+/// let (merged, substituted) = merge_lossy_2bit(a, a_mask, b, b_mask, 4)?;
+/// assert_eq!(merged, as_2bit(b"ACGA")?);
+/// assert_eq!(substituted, 0b1000);
+/// # Ok(())
+/// # }
+/// ```
+pub fn merge_lossy_2bit(
+    a: u64,
+    a_mask: u32,
+    b: u64,
+    b_mask: u32,
+    len: usize,
+) -> Result<(u64, u32), NucleotideError> {
+    if len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    let take_b = expand_base_mask(a_mask & !b_mask);
+    let merged = ((a & !take_b) | (b & take_b)) & window_mask(len);
+    let len_mask = 1u32.checked_shl(len as u32).map_or(u32::MAX, |bit| bit - 1);
+    Ok((merged, a_mask & b_mask & len_mask))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_as_2bit_lossy_with_g() {
+        let (packed, substituted) = as_2bit_lossy_with(b"NACRTn", b'G').unwrap();
+        assert_eq!(packed, as_2bit(b"GACGTG").unwrap());
+        assert_eq!(substituted, 0b101001);
+
+        let (packed, substituted) = as_2bit_lossy_with(b"\xffAC", b'g').unwrap();
+        assert_eq!(packed, as_2bit(b"GAC").unwrap());
+        assert_eq!(substituted, 0b001);
+    }
+
+    #[test]
+    fn test_as_2bit_lossy_with_clean() {
+        let seq = b"ACGTTGCAACGTTGCAACGTTGCAACGTTGCA";
+        assert_eq!(
+            as_2bit_lossy_with(seq, b'T'),
+            Ok((as_2bit(seq).unwrap(), 0))
+        );
+        assert_eq!(
+            as_2bit_lossy_with(&[b'N'; 32], b'T'),
+            Ok((u64::MAX, u32::MAX))
+        );
+        assert_eq!(as_2bit_lossy_with(b"", b'A'), Ok((0, 0)));
+    }
+
+    #[test]
+    fn test_as_2bit_lossy_with_errors() {
+        assert_eq!(
+            as_2bit_lossy_with(b"ACGT", b'N'),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        assert_eq!(
+            as_2bit_lossy_with(&[b'A'; 33], b'A'),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+
+    #[test]
+    fn test_merge_lossy_complementary_masks() {
+        let (a, a_mask) = as_2bit_lossy_with(b"GANTNCNA", b'T').unwrap();
+        let (b, b_mask) = as_2bit_lossy_with(b"NNCNANGN", b'T').unwrap();
+        assert_eq!(a_mask, !b_mask & 0xFF);
+
+        let (merged, substituted) = merge_lossy_2bit(a, a_mask, b, b_mask, 8).unwrap();
+        assert_eq!(merged, as_2bit(b"GACTACGA").unwrap());
+        assert_eq!(substituted, 0);
+
+        // Merging is symmetric when every position is resolved by one side
+        assert_eq!(
+            merge_lossy_2bit(b, b_mask, a, a_mask, 8),
+            Ok((merged, substituted))
+        );
+    }
+
+    #[test]
+    fn test_merge_lossy_conflicts() {
+        // Both real: a wins. Both substituted: a is kept and the position stays marked
+        let (a, a_mask) = as_2bit_lossy_with(b"ACNN", b'G').unwrap();
+        let (b, b_mask) = as_2bit_lossy_with(b"TTTN", b'C').unwrap();
+        assert_eq!(
+            merge_lossy_2bit(a, a_mask, b, b_mask, 4),
+            Ok((as_2bit(b"ACTG").unwrap(), 0b1000))
+        );
+
+        let (merged, substituted) = merge_lossy_2bit(0, u32::MAX, u64::MAX, 0, 32).unwrap();
+        assert_eq!((merged, substituted), (u64::MAX, 0));
+        assert_eq!(
+            merge_lossy_2bit(u64::MAX, u32::MAX, 0, u32::MAX, 3),
+            Ok((0b111111, 0b111))
+        );
+        assert_eq!(
+            merge_lossy_2bit(0, 0, 0, 0, 33),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
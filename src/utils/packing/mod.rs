@@ -2,11 +2,49 @@ use crate::NucleotideError;
 
 #[cfg(all(target_arch = "aarch64", not(feature = "nosimd")))]
 mod aarch64;
+mod adapter;
+mod alphabet;
 #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
 mod avx;
+mod backend;
+mod batch;
+mod bitsliced;
+mod bytes;
+mod delimited;
+mod fixed;
+mod iupac;
+mod lookup;
+mod lossy;
 mod naive;
+mod nucleic;
+mod record;
+mod reversed;
+mod sentinel;
 #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
 mod sse;
+mod whitespace;
+mod wide;
+
+pub use adapter::{PackExt, PackedMap};
+pub use alphabet::as_2bit_with_alphabet;
+pub(crate) use alphabet::validate_alphabet;
+pub use backend::{active_backend, as_2bit_with_backend, Backend};
+pub use batch::as_2bit_batch_uniform;
+pub use bitsliced::as_2bit_bitsliced;
+pub use bytes::as_2bit_bytes;
+pub use delimited::as_2bit_split;
+pub use fixed::as_2bit_fixed;
+pub use iupac::{as_2bit_iupac_lossy, as_2bit_one_ambiguous, expand_iupac};
+pub use lookup::{build_lookup, ASCII_TO_CODE, COMPLEMENT_CODE, INVALID_CODE};
+pub use lossy::{as_2bit_lossy_with, merge_lossy_2bit};
+pub(crate) use naive::base_to_2bit;
+pub use naive::complement_base;
+pub use nucleic::as_2bit_nucleic;
+pub use record::{pack_fasta_sequence, pack_fastq_record};
+pub use reversed::as_2bit_reversed;
+pub use sentinel::{as_2bit_with_sentinel, base_at_with_sentinel, SENTINEL};
+pub use whitespace::{as_2bit_ignore_whitespace, as_2bit_with_coords};
+pub use wide::as_2bit_u128;
 
 /// Converts a nucleotide sequence into a 2-bit packed representation.
 ///
@@ -29,9 +67,12 @@ mod sse;
 ///
 /// # Errors
 ///
-/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any ASCII characters
 /// other than A,C,G,T (case insensitive).
 ///
+/// Returns `NucleotideError::NonAscii` if the sequence contains any bytes with the
+/// high bit set.
+///
 /// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
 /// than 32 bases (as a u64 can only store 32 * 2 bits).
 ///
@@ -79,6 +120,40 @@ mod sse;
 /// ```
 #[inline(always)]
 pub fn as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
+    let packed = dispatch_as_2bit(seq);
+    if VERIFY_SIMD {
+        verify_against_naive(seq, &packed);
+    }
+    packed
+}
+
+/// Whether `as_2bit` cross-checks its result against the naive implementation.
+///
+/// Enabled by the `debug-verify` feature, and only in builds with debug assertions.
+const VERIFY_SIMD: bool = cfg!(all(feature = "debug-verify", debug_assertions));
+
+#[cfg(test)]
+thread_local! {
+    /// Number of cross-checks performed on this thread
+    static VERIFY_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Panics in debug builds if the naive packer disagrees with `packed`.
+#[inline(never)]
+fn verify_against_naive(seq: &[u8], packed: &Result<u64, NucleotideError>) {
+    #[cfg(test)]
+    VERIFY_CALLS.with(|calls| calls.set(calls.get() + 1));
+
+    debug_assert_eq!(
+        packed,
+        &naive::as_2bit(seq),
+        "SIMD and naive packing diverged for {:?}",
+        seq
+    );
+}
+
+#[inline(always)]
+fn dispatch_as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
     #[cfg(all(target_arch = "aarch64", not(feature = "nosimd")))]
     if std::arch::is_aarch64_feature_detected!("neon") {
         aarch64::as_2bit(seq)
@@ -144,6 +219,7 @@ pub fn encode_internal(seq: &[u8], ebuf: &mut Vec<u64>) -> Result<(), Nucleotide
 #[cfg(test)]
 mod testing {
     use super::*;
+    use crate::{MAX_BASES, MAX_BASES_U128};
 
     #[test]
     fn test_as_2bit_valid_sequence() {
@@ -187,6 +263,55 @@ mod testing {
         assert!(matches!(result, Err(NucleotideError::InvalidBase(b'N'))));
     }
 
+    #[test]
+    fn test_as_2bit_max_bases() {
+        assert!(as_2bit(&[b'T'; MAX_BASES]).is_ok());
+        assert_eq!(
+            as_2bit(&[b'T'; MAX_BASES + 1]),
+            Err(NucleotideError::SequenceTooLong(MAX_BASES + 1))
+        );
+        assert!(as_2bit_u128(&[b'T'; MAX_BASES_U128]).is_ok());
+        assert_eq!(
+            as_2bit_u128(&[b'T'; MAX_BASES_U128 + 1]),
+            Err(NucleotideError::SequenceTooLong(MAX_BASES_U128 + 1))
+        );
+    }
+
+    #[test]
+    fn test_as_2bit_non_ascii() {
+        // Exercise both the SIMD and scalar remainder paths
+        for len in [4, 16, 31, 32] {
+            let mut seq = vec![b'A'; len];
+            seq[len - 1] = 0xFF;
+            assert_eq!(as_2bit(&seq), Err(NucleotideError::NonAscii(0xFF)));
+
+            seq[len - 1] = b'N';
+            assert_eq!(as_2bit(&seq), Err(NucleotideError::InvalidBase(b'N')));
+        }
+
+        let mut seq = vec![b'C'; 40];
+        seq[35] = 0x80;
+        assert_eq!(
+            crate::encode_alloc(&seq),
+            Err(NucleotideError::NonAscii(0x80))
+        );
+    }
+
+    #[test]
+    fn test_debug_verify() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAA";
+        let before = VERIFY_CALLS.with(|calls| calls.get());
+        for len in 0..=seq.len() {
+            assert_eq!(as_2bit(&seq[..len]), naive::as_2bit(&seq[..len]));
+        }
+        assert_eq!(as_2bit(b"ACGN"), Err(NucleotideError::InvalidBase(b'N')));
+
+        // Cross-checks only run with the feature enabled
+        let calls = VERIFY_CALLS.with(|calls| calls.get()) - before;
+        let expected = if VERIFY_SIMD { seq.len() + 2 } else { 0 };
+        assert_eq!(calls, expected);
+    }
+
     #[test]
     fn test_as_2bit_sequence_too_long() {
         let long_seq = vec![b'A'; 33];
@@ -1,24 +1,60 @@
+use super::lookup::{ASCII_TO_CODE, INVALID_CODE};
 use crate::error::NucleotideError;
+use crate::MAX_BASES;
 
 #[inline(always)]
 pub fn as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
-    if seq.len() > 32 {
+    if seq.len() > MAX_BASES {
         return Err(NucleotideError::SequenceTooLong(seq.len()));
     }
     let mut packed = 0u64;
     for (i, &base) in seq.iter().enumerate() {
-        let bits = match base {
-            b'A' | b'a' => 0b00,
-            b'C' | b'c' => 0b01,
-            b'G' | b'g' => 0b10,
-            b'T' | b't' => 0b11,
-            invalid => return Err(NucleotideError::InvalidBase(invalid)),
-        };
-        packed |= (bits as u64) << (i * 2);
+        packed |= base_to_2bit(base)? << (i * 2);
     }
     Ok(packed)
 }
 
+/// Converts a single ASCII nucleotide (case insensitive) into its 2-bit code.
+#[inline(always)]
+pub fn base_to_2bit(base: u8) -> Result<u64, NucleotideError> {
+    match ASCII_TO_CODE[base as usize] {
+        INVALID_CODE => Err(NucleotideError::invalid_byte(base)),
+        code => Ok(code as u64),
+    }
+}
+
+/// Returns the ASCII complement of a nucleotide, preserving case.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if `base` is not one of A,C,G,T (case insensitive).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::complement_base;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(complement_base(b'A')?, b'T');
+/// assert_eq!(complement_base(b'g')?, b'c');
+/// assert!(complement_base(b'N').is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn complement_base(base: u8) -> Result<u8, NucleotideError> {
+    match base {
+        b'A' => Ok(b'T'),
+        b'C' => Ok(b'G'),
+        b'G' => Ok(b'C'),
+        b'T' => Ok(b'A'),
+        b'a' => Ok(b't'),
+        b'c' => Ok(b'g'),
+        b'g' => Ok(b'c'),
+        b't' => Ok(b'a'),
+        _ => Err(NucleotideError::invalid_byte(base)),
+    }
+}
+
 pub fn encode_internal(sequence: &[u8], ebuf: &mut Vec<u64>) -> Result<(), NucleotideError> {
     // Clear the buffer
     ebuf.clear();
@@ -41,3 +77,45 @@ pub fn encode_internal(sequence: &[u8], ebuf: &mut Vec<u64>) -> Result<(), Nucle
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complement_base() {
+        let pairs = [(b'A', b'T'), (b'C', b'G'), (b'G', b'C'), (b'T', b'A')];
+        for (base, complement) in pairs {
+            assert_eq!(complement_base(base), Ok(complement));
+            assert_eq!(
+                complement_base(base.to_ascii_lowercase()),
+                Ok(complement.to_ascii_lowercase())
+            );
+        }
+        assert_eq!(complement_base(b'a'), Ok(b't'));
+    }
+
+    #[test]
+    fn test_complement_base_matches_2bit() {
+        for base in *b"ACGTacgt" {
+            let complement = complement_base(base).unwrap();
+            assert_eq!(
+                base_to_2bit(complement),
+                Ok(!base_to_2bit(base).unwrap() & 0b11)
+            );
+        }
+    }
+
+    #[test]
+    fn test_complement_base_invalid() {
+        assert_eq!(
+            complement_base(b'N'),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        assert_eq!(
+            complement_base(b'U'),
+            Err(NucleotideError::InvalidBase(b'U'))
+        );
+        assert_eq!(complement_base(0xFF), Err(NucleotideError::NonAscii(0xFF)));
+    }
+}
@@ -0,0 +1,108 @@
+use super::naive::base_to_2bit;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Converts a DNA or RNA sequence into a 2-bit packed representation.
+///
+/// Both `T` and `U` are packed as `0b11`, so DNA and RNA inputs share a single
+/// encoding. Sequences containing both `T` and `U` usually point to a data
+/// problem, and can optionally be rejected.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T,U, case insensitive)
+/// * `reject_mixed` - Whether to error if both `T` and `U` occur in `seq`
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the input contains any characters
+/// other than A,C,G,T,U (case insensitive).
+///
+/// Returns `NucleotideError::MixedAlphabet` if `reject_mixed` is set and the
+/// sequence contains both `T` and `U`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_nucleic, NucleotideError};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(as_2bit_nucleic(b"ACGU", true)?, as_2bit(b"ACGT")?);
+/// assert_eq!(as_2bit_nucleic(b"TACGU", true), Err(NucleotideError::MixedAlphabet));
+/// assert_eq!(as_2bit_nucleic(b"TACGU", false)?, as_2bit(b"TACGT")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_nucleic(seq: &[u8], reject_mixed: bool) -> Result<u64, NucleotideError> {
+    if seq.len() > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    let mut packed = 0u64;
+    let mut seen_t = false;
+    let mut seen_u = false;
+    for (i, &base) in seq.iter().enumerate() {
+        let bits = match base {
+            b'U' | b'u' => {
+                seen_u = true;
+                0b11
+            }
+            b'T' | b't' => {
+                seen_t = true;
+                0b11
+            }
+            _ => base_to_2bit(base)?,
+        };
+        packed |= bits << (i * 2);
+    }
+
+    if reject_mixed && seen_t && seen_u {
+        return Err(NucleotideError::MixedAlphabet);
+    }
+    Ok(packed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_as_2bit_nucleic_dna() {
+        let expected = as_2bit(b"ACGTTGCA").unwrap();
+        assert_eq!(as_2bit_nucleic(b"ACGTTGCA", true), Ok(expected));
+        assert_eq!(as_2bit_nucleic(b"acgttgca", false), Ok(expected));
+    }
+
+    #[test]
+    fn test_as_2bit_nucleic_rna() {
+        let expected = as_2bit(b"ACGTTGCA").unwrap();
+        assert_eq!(as_2bit_nucleic(b"ACGUUGCA", true), Ok(expected));
+        assert_eq!(as_2bit_nucleic(b"acguugca", false), Ok(expected));
+    }
+
+    #[test]
+    fn test_as_2bit_nucleic_mixed() {
+        assert_eq!(
+            as_2bit_nucleic(b"ACGTUGCA", true),
+            Err(NucleotideError::MixedAlphabet)
+        );
+        assert_eq!(
+            as_2bit_nucleic(b"ACGTUGCA", false),
+            Ok(as_2bit(b"ACGTTGCA").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_as_2bit_nucleic_errors() {
+        assert_eq!(
+            as_2bit_nucleic(b"ACGN", false),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        assert_eq!(
+            as_2bit_nucleic(&[b'U'; 33], false),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+}
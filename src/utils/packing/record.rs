@@ -0,0 +1,271 @@
+use super::{as_2bit, encode_internal};
+use crate::NucleotideError;
+
+/// Splits a raw record into lines, accepting both `\n` and `\r\n` line endings.
+///
+/// A single trailing line terminator does not produce an empty final line.
+fn record_lines(record: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let record = record.strip_suffix(b"\n").unwrap_or(record);
+    record
+        .split(|&b| b == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+/// Packs the sequence line of a raw four-line FASTQ record into 2-bit blocks.
+///
+/// The record must consist of a header line starting with `@`, a sequence line,
+/// a separator line starting with `+`, and a quality line of the same length as
+/// the sequence. Both `\n` and `\r\n` line endings are accepted, and a trailing
+/// line terminator is optional.
+///
+/// # Arguments
+///
+/// * `record` - The raw bytes of a single FASTQ record
+///
+/// # Returns
+///
+/// Returns the encoded blocks (laid out as by [`encode`](crate::encode)) and the
+/// number of bases in the sequence.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::MalformedRecord` if the record does not follow the
+/// four-line FASTQ layout.
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence line contains any characters
+/// other than A,C,G,T (case insensitive).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{decode, pack_fastq_record};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let record = b"@read1\nACGTTGCA\n+\nIIIIIIII\n";
+/// let (blocks, n_bases) = pack_fastq_record(record)?;
+/// assert_eq!(n_bases, 8);
+///
+/// let mut seq = Vec::new();
+/// decode(&blocks, n_bases, &mut seq)?;
+/// assert_eq!(&seq, b"ACGTTGCA");
+/// # Ok(())
+/// # }
+/// ```
+pub fn pack_fastq_record(record: &[u8]) -> Result<(Vec<u64>, usize), NucleotideError> {
+    let mut lines = record_lines(record);
+    let (Some(header), Some(seq), Some(sep), Some(qual), None) = (
+        lines.next(),
+        lines.next(),
+        lines.next(),
+        lines.next(),
+        lines.next(),
+    ) else {
+        return Err(NucleotideError::MalformedRecord);
+    };
+    if !header.starts_with(b"@") || !sep.starts_with(b"+") || seq.len() != qual.len() {
+        return Err(NucleotideError::MalformedRecord);
+    }
+
+    let mut blocks = Vec::with_capacity(seq.len().div_ceil(32));
+    if !seq.is_empty() {
+        encode_internal(seq, &mut blocks)?;
+    }
+    Ok((blocks, seq.len()))
+}
+
+/// Packs a multi-line FASTA sequence body into 2-bit blocks, skipping line breaks.
+///
+/// Blocks are appended to `out` laid out as by [`encode`](crate::encode): every
+/// block holds 32 bases except the last, which holds the remainder. Runs of 32
+/// bases within a line are packed straight from the input with the SIMD packer,
+/// and only bases straddling line breaks are copied through a small buffer.
+///
+/// Both `\n` and `\r\n` line endings are accepted.
+///
+/// # Arguments
+///
+/// * `seq` - The sequence lines of a FASTA record (without the `>` header)
+/// * `out` - The buffer the packed blocks are appended to
+///
+/// # Returns
+///
+/// Returns the number of bases packed.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// other than A,C,G,T (case insensitive) or line breaks. Blocks already appended
+/// to `out` are left in place.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{decode, pack_fasta_sequence};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut blocks = Vec::new();
+/// let n_bases = pack_fasta_sequence(b"ACGT\nTTGC\nA\n", &mut blocks)?;
+/// assert_eq!(n_bases, 9);
+///
+/// let mut seq = Vec::new();
+/// decode(&blocks, n_bases, &mut seq)?;
+/// assert_eq!(&seq, b"ACGTTTGCA");
+/// # Ok(())
+/// # }
+/// ```
+pub fn pack_fasta_sequence(seq: &[u8], out: &mut Vec<u64>) -> Result<usize, NucleotideError> {
+    let mut carry = [0u8; 32];
+    let mut n_carry = 0;
+    let mut n_bases = 0;
+
+    for mut line in record_lines(seq) {
+        n_bases += line.len();
+
+        // Complete a block left over from previous lines
+        if n_carry > 0 {
+            let take = line.len().min(32 - n_carry);
+            carry[n_carry..n_carry + take].copy_from_slice(&line[..take]);
+            n_carry += take;
+            line = &line[take..];
+            if n_carry < 32 {
+                continue;
+            }
+            out.push(as_2bit(&carry)?);
+        }
+
+        // Pack full blocks directly from the line, carrying the remainder
+        let mut chunks = line.chunks_exact(32);
+        for chunk in &mut chunks {
+            out.push(as_2bit(chunk)?);
+        }
+        let rem = chunks.remainder();
+        carry[..rem.len()].copy_from_slice(rem);
+        n_carry = rem.len();
+    }
+
+    if n_carry > 0 {
+        out.push(as_2bit(&carry[..n_carry])?);
+    }
+    Ok(n_bases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode_alloc};
+
+    const SEQ: &[u8] = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAACCGTAGCTAG";
+
+    fn record(line_ending: &str) -> Vec<u8> {
+        let seq = std::str::from_utf8(SEQ).unwrap();
+        let qual = "I".repeat(SEQ.len());
+        format!(
+            "@read1 sample{le}{seq}{le}+{le}{qual}{le}",
+            le = line_ending
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_pack_fastq_record() {
+        let (blocks, n_bases) = pack_fastq_record(&record("\n")).unwrap();
+        assert_eq!(n_bases, SEQ.len());
+        assert_eq!(blocks, encode_alloc(SEQ).unwrap());
+
+        let mut dbuf = Vec::new();
+        decode(&blocks, n_bases, &mut dbuf).unwrap();
+        assert_eq!(dbuf, SEQ);
+    }
+
+    #[test]
+    fn test_pack_fastq_record_line_endings() {
+        let expected = encode_alloc(SEQ).unwrap();
+        for rec in [record("\r\n"), record("\n")] {
+            assert_eq!(pack_fastq_record(&rec), Ok((expected.clone(), SEQ.len())));
+
+            // Trailing terminator is optional
+            let trimmed = rec.trim_ascii_end();
+            assert_eq!(
+                pack_fastq_record(trimmed),
+                Ok((expected.clone(), SEQ.len()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_pack_fastq_record_malformed() {
+        let malformed: [&[u8]; 5] = [
+            b"",
+            b"@read1\nACGT\n+\n",
+            b">read1\nACGT\n+\nIIII\n",
+            b"@read1\nACGT\n-\nIIII\n",
+            b"@read1\nACGT\n+\nIII\n",
+        ];
+        for rec in malformed {
+            assert_eq!(
+                pack_fastq_record(rec),
+                Err(NucleotideError::MalformedRecord)
+            );
+        }
+        assert_eq!(
+            pack_fastq_record(b"@read1\nACGT\n+\nIIII\n@read2\n"),
+            Err(NucleotideError::MalformedRecord)
+        );
+    }
+
+    #[test]
+    fn test_pack_fastq_record_invalid_base() {
+        assert_eq!(
+            pack_fastq_record(b"@read1\nACNT\n+\nIIII\n"),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        assert_eq!(pack_fastq_record(b"@read1\n\n+\n\n"), Ok((vec![], 0)));
+    }
+
+    #[test]
+    fn test_pack_fasta_sequence() {
+        let seq: Vec<u8> = (0..250).map(|i| b"ACGTTGCA"[(i * 5 + i / 3) % 8]).collect();
+        for width in [1, 7, 31, 32, 33, 60, 80, 250] {
+            let mut body = Vec::new();
+            for line in seq.chunks(width) {
+                body.extend_from_slice(line);
+                body.extend_from_slice(b"\n");
+            }
+
+            let mut blocks = Vec::new();
+            let n_bases = pack_fasta_sequence(&body, &mut blocks).unwrap();
+            assert_eq!(n_bases, seq.len());
+            assert_eq!(
+                blocks,
+                encode_alloc(&seq).unwrap(),
+                "Failed for width {}",
+                width
+            );
+
+            let mut dbuf = Vec::new();
+            decode(&blocks, n_bases, &mut dbuf).unwrap();
+            assert_eq!(dbuf, seq);
+        }
+    }
+
+    #[test]
+    fn test_pack_fasta_sequence_line_endings() {
+        let mut blocks = Vec::new();
+        let n_bases = pack_fasta_sequence(b"ACGTACGTAC\r\nGGGG\r\n\r\nTT", &mut blocks).unwrap();
+        assert_eq!(n_bases, 16);
+        assert_eq!(blocks, encode_alloc(b"ACGTACGTACGGGGTT").unwrap());
+    }
+
+    #[test]
+    fn test_pack_fasta_sequence_appends() {
+        let mut blocks = vec![u64::MAX];
+        assert_eq!(pack_fasta_sequence(b"", &mut blocks), Ok(0));
+        assert_eq!(pack_fasta_sequence(b"AC\nGT", &mut blocks), Ok(4));
+        assert_eq!(blocks, vec![u64::MAX, as_2bit(b"ACGT").unwrap()]);
+
+        assert_eq!(
+            pack_fasta_sequence(b"ACGT\nNNNN\n", &mut blocks),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use super::naive::base_to_2bit;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Converts a nucleotide sequence stored 3' to 5' into a 2-bit packed u64.
+///
+/// Bases are packed in reverse order without being complemented, so the first
+/// input base lands at the highest position (`seq.len() - 1`) and the last input
+/// base at position 0. Unpacking the result yields the sequence 5' to 3'.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// other than A,C,G,T (case insensitive).
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
+/// than 32 bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_reversed};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(as_2bit_reversed(b"AACG")?, as_2bit(b"GCAA")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_reversed(seq: &[u8]) -> Result<u64, NucleotideError> {
+    if seq.len() > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    seq.iter()
+        .rev()
+        .enumerate()
+        .try_fold(0u64, |packed, (i, &base)| {
+            Ok(packed | (base_to_2bit(base)? << (i * 2)))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_2bit;
+
+    #[test]
+    fn test_as_2bit_reversed() {
+        let packed = as_2bit_reversed(b"ACGT").unwrap();
+        let mut unpacked = Vec::new();
+        from_2bit(packed, 4, &mut unpacked).unwrap();
+        assert_eq!(&unpacked, b"TGCA");
+    }
+
+    #[test]
+    fn test_as_2bit_reversed_edge_cases() {
+        assert_eq!(as_2bit_reversed(b""), Ok(0));
+        assert_eq!(as_2bit_reversed(b"g"), Ok(0b10));
+
+        // The first base lands at the highest position
+        let mut seq = [b'A'; 32];
+        seq[0] = b'T';
+        assert_eq!(as_2bit_reversed(&seq), Ok(0b11 << 62));
+
+        assert_eq!(
+            as_2bit_reversed(&[b'A'; 33]),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+        assert_eq!(
+            as_2bit_reversed(b"ACNT"),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+}
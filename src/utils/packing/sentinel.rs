@@ -0,0 +1,138 @@
+use super::as_2bit;
+use crate::utils::functions::base_at_2bit;
+use crate::{NucleotideError, MAX_BASES};
+
+/// The marker reported for the sentinel position by [`base_at_with_sentinel`]
+pub const SENTINEL: u8 = b'$';
+
+/// Converts a nucleotide sequence of up to 31 bases into a 2-bit packed u64
+/// followed by a logical sentinel.
+///
+/// 2-bit encoding has no room for a fifth symbol, so the sentinel is carried by
+/// the length alone: the returned length is `seq.len() + 1`, and the last
+/// position of that length is the sentinel. Its two bits in the packed value
+/// are zero and must not be read as a base. Companion functions such as
+/// [`base_at_with_sentinel`] take the returned length as-is and report the
+/// sentinel position as [`SENTINEL`].
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Returns
+///
+/// Returns the packed value and the sentinel-terminated length.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// other than A,C,G,T (case insensitive).
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
+/// than 31 bases (leaving no position for the sentinel).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_with_sentinel};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (packed, len) = as_2bit_with_sentinel(b"ACGT")?;
+/// assert_eq!(packed, as_2bit(b"ACGT")?);
+/// assert_eq!(len, 5);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_with_sentinel(seq: &[u8]) -> Result<(u64, usize), NucleotideError> {
+    if seq.len() > MAX_BASES - 1 {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+    Ok((as_2bit(seq)?, seq.len() + 1))
+}
+
+/// Returns the ASCII nucleotide at a position of a sentinel-terminated sequence.
+///
+/// The last position, `len - 1`, is the sentinel and is returned as [`SENTINEL`].
+/// See [`as_2bit_with_sentinel`] for the convention.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `len` - The sentinel-terminated length, as returned by [`as_2bit_with_sentinel`]
+/// * `index` - The position of the base to extract
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `len` is 0 or greater than 32.
+/// Returns `NucleotideError::IndexOutOfBounds` if `index` is not less than `len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit_with_sentinel, base_at_with_sentinel, SENTINEL};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (packed, len) = as_2bit_with_sentinel(b"GA")?;
+/// assert_eq!(base_at_with_sentinel(packed, len, 1)?, b'A');
+/// assert_eq!(base_at_with_sentinel(packed, len, 2)?, SENTINEL);
+/// # Ok(())
+/// # }
+/// ```
+pub fn base_at_with_sentinel(packed: u64, len: usize, index: usize) -> Result<u8, NucleotideError> {
+    if len == 0 || len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+    if index == len - 1 {
+        return Ok(SENTINEL);
+    }
+    base_at_2bit(packed, len, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentinel_position() {
+        let seq = b"AAAA";
+        let (packed, len) = as_2bit_with_sentinel(seq).unwrap();
+        assert_eq!(len, 5);
+        for i in 0..4 {
+            assert_eq!(base_at_with_sentinel(packed, len, i), Ok(b'A'));
+        }
+        // The sentinel bits read as A but are reported distinctly
+        assert_eq!(base_at_with_sentinel(packed, len, 4), Ok(SENTINEL));
+        assert_eq!(
+            base_at_with_sentinel(packed, len, 5),
+            Err(NucleotideError::IndexOutOfBounds {
+                index: 5,
+                length: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_sentinel_bounds() {
+        let seq = [b'T'; 31];
+        let (packed, len) = as_2bit_with_sentinel(&seq).unwrap();
+        assert_eq!(len, 32);
+        assert_eq!(base_at_with_sentinel(packed, len, 30), Ok(b'T'));
+        assert_eq!(base_at_with_sentinel(packed, len, 31), Ok(SENTINEL));
+
+        let (_, len) = as_2bit_with_sentinel(b"").unwrap();
+        assert_eq!(base_at_with_sentinel(0, len, 0), Ok(SENTINEL));
+
+        assert_eq!(
+            as_2bit_with_sentinel(&[b'A'; 32]),
+            Err(NucleotideError::SequenceTooLong(32))
+        );
+        assert_eq!(
+            base_at_with_sentinel(0, 0, 0),
+            Err(NucleotideError::InvalidLength(0))
+        );
+        assert_eq!(
+            base_at_with_sentinel(0, 33, 0),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -1,5 +1,6 @@
+use super::lookup::{ASCII_TO_CODE, INVALID_CODE};
 use super::naive;
-use crate::NucleotideError;
+use crate::{NucleotideError, MAX_BASES};
 use std::arch::x86_64::*;
 
 #[repr(u8)]
@@ -77,7 +78,7 @@ unsafe fn process_simd_chunk(chunk: __m128i, constants: &SimdConstants) -> __m12
 }
 
 pub fn as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
-    if seq.len() > 32 {
+    if seq.len() > MAX_BASES {
         return Err(NucleotideError::SequenceTooLong(seq.len()));
     }
 
@@ -89,9 +90,9 @@ pub fn as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
     // Pre-validate bases
     if let Some(&invalid) = seq
         .iter()
-        .find(|&&b| !matches!(b, b'A' | b'a' | b'C' | b'c' | b'G' | b'g' | b'T' | b't'))
+        .find(|&&b| ASCII_TO_CODE[b as usize] == INVALID_CODE)
     {
-        return Err(NucleotideError::InvalidBase(invalid));
+        return Err(NucleotideError::invalid_byte(invalid));
     }
 
     let mut packed = 0u64;
@@ -117,13 +118,7 @@ pub fn as_2bit(seq: &[u8]) -> Result<u64, NucleotideError> {
 
         // Handle remaining bases the same way as AARCH64
         for (i, &base) in seq.iter().skip(simd_len).enumerate() {
-            let bits = match base {
-                b'A' | b'a' => NucleotideBits::A as u64,
-                b'C' | b'c' => NucleotideBits::C as u64,
-                b'G' | b'g' => NucleotideBits::G as u64,
-                b'T' | b't' => NucleotideBits::T as u64,
-                _ => unreachable!(),
-            };
+            let bits = ASCII_TO_CODE[base as usize] as u64;
             packed |= bits << ((simd_len + i) * 2);
         }
     }
@@ -0,0 +1,172 @@
+use super::naive::base_to_2bit;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Returns true for the whitespace bytes found in wrapped sequence files
+#[inline(always)]
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\n' | b'\r' | b'\t' | b' ')
+}
+
+/// Converts a nucleotide sequence into a 2-bit packed representation, skipping whitespace.
+///
+/// Newlines (`\n`, `\r`), tabs, and spaces are ignored so that line-wrapped
+/// sequences (e.g. from FASTA files) can be packed without a separate cleaning pass.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive) and whitespace
+///
+/// # Returns
+///
+/// Returns the packed `u64` and the number of bases packed.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input contains more than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the input contains any characters
+/// other than A,C,G,T (case insensitive) or whitespace.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_ignore_whitespace};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (packed, n_bases) = as_2bit_ignore_whitespace(b"AC\nGT\r\n")?;
+/// assert_eq!(packed, as_2bit(b"ACGT")?);
+/// assert_eq!(n_bases, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_ignore_whitespace(seq: &[u8]) -> Result<(u64, usize), NucleotideError> {
+    let n_bases = seq.iter().filter(|&&b| !is_whitespace(b)).count();
+    if n_bases > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(n_bases));
+    }
+
+    let mut packed = 0u64;
+    for (i, &base) in seq.iter().filter(|&&b| !is_whitespace(b)).enumerate() {
+        packed |= base_to_2bit(base)? << (i * 2);
+    }
+    Ok((packed, n_bases))
+}
+
+/// Converts a nucleotide sequence into a 2-bit packed representation, skipping
+/// whitespace and recording where each base came from.
+///
+/// Whitespace is skipped exactly as in [`as_2bit_ignore_whitespace`]. Alongside the
+/// packed value, the byte offset in `seq` of every packed base is returned so that
+/// positions in the packed sequence can be translated back to the original input.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive) and whitespace
+///
+/// # Returns
+///
+/// Returns the packed `u64`, the number of bases packed, and the original byte
+/// offset of each packed base.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input contains more than 32 bases.
+///
+/// Returns `NucleotideError::InvalidBase` if the input contains any characters
+/// other than A,C,G,T (case insensitive) or whitespace.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_with_coords};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (packed, n_bases, coords) = as_2bit_with_coords(b"AC\nGT")?;
+/// assert_eq!(packed, as_2bit(b"ACGT")?);
+/// assert_eq!(n_bases, 4);
+/// assert_eq!(coords, vec![0, 1, 3, 4]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_with_coords(seq: &[u8]) -> Result<(u64, usize, Vec<usize>), NucleotideError> {
+    let coords: Vec<usize> = seq
+        .iter()
+        .enumerate()
+        .filter(|(_, &b)| !is_whitespace(b))
+        .map(|(offset, _)| offset)
+        .collect();
+    if coords.len() > MAX_BASES {
+        return Err(NucleotideError::SequenceTooLong(coords.len()));
+    }
+
+    let mut packed = 0u64;
+    for (i, &offset) in coords.iter().enumerate() {
+        packed |= base_to_2bit(seq[offset])? << (i * 2);
+    }
+    Ok((packed, coords.len(), coords))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_wrapped_sequence() {
+        let wrapped = b"ACTGGAAAAT\nTTTAAGGACT\r\nGGAA\tAATT TTAA\n";
+        let unwrapped = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAA";
+
+        let (packed, n_bases) = as_2bit_ignore_whitespace(wrapped).unwrap();
+        assert_eq!(packed, as_2bit(unwrapped).unwrap());
+        assert_eq!(n_bases, unwrapped.len());
+    }
+
+    #[test]
+    fn test_whitespace_only() {
+        assert_eq!(as_2bit_ignore_whitespace(b"\n\r\n \t"), Ok((0, 0)));
+    }
+
+    #[test]
+    fn test_invalid_base() {
+        assert_eq!(
+            as_2bit_ignore_whitespace(b"AC\nGN"),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+    }
+
+    #[test]
+    fn test_too_many_bases() {
+        let mut seq = vec![b'A'; 30];
+        seq.extend_from_slice(b"\nACG\n");
+        assert_eq!(
+            as_2bit_ignore_whitespace(&seq),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+
+    #[test]
+    fn test_with_coords() {
+        let wrapped = b"ACG\nTT\r\nGA";
+        let (packed, n_bases, coords) = as_2bit_with_coords(wrapped).unwrap();
+        assert_eq!(packed, as_2bit(b"ACGTTGA").unwrap());
+        assert_eq!(n_bases, 7);
+        assert_eq!(coords, vec![0, 1, 2, 4, 5, 8, 9]);
+        for (i, &offset) in coords.iter().enumerate() {
+            assert_eq!(wrapped[offset], b"ACGTTGA"[i]);
+        }
+    }
+
+    #[test]
+    fn test_with_coords_errors() {
+        assert_eq!(
+            as_2bit_with_coords(b"AC\nGN"),
+            Err(NucleotideError::InvalidBase(b'N'))
+        );
+        let mut seq = vec![b'A'; 30];
+        seq.extend_from_slice(b"\nACG\n");
+        assert_eq!(
+            as_2bit_with_coords(&seq),
+            Err(NucleotideError::SequenceTooLong(33))
+        );
+    }
+}
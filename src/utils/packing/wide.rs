@@ -0,0 +1,70 @@
+use super::as_2bit;
+use crate::{NucleotideError, MAX_BASES_U128};
+
+/// Converts a nucleotide sequence of up to 64 bases into a 2-bit packed `u128`.
+///
+/// This mirrors [`as_2bit`] with the limit raised to 64 bases. Bases are packed
+/// from least significant to most significant bits, so the first 32 bases occupy
+/// the lower 64 bits exactly as `as_2bit` would pack them.
+///
+/// # Arguments
+///
+/// * `seq` - A byte slice containing ASCII nucleotides (A,C,G,T, case insensitive)
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidBase` if the sequence contains any characters
+/// other than A,C,G,T (case insensitive).
+///
+/// Returns `NucleotideError::SequenceTooLong` if the input sequence is longer
+/// than 64 bases (as a u128 can only store 64 * 2 bits).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, as_2bit_u128};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit_u128(b"ACGT")?;
+/// assert_eq!(packed, as_2bit(b"ACGT")? as u128);
+///
+/// let seq = [b'T'; 64];
+/// assert_eq!(as_2bit_u128(&seq)?, u128::MAX);
+/// # Ok(())
+/// # }
+/// ```
+pub fn as_2bit_u128(seq: &[u8]) -> Result<u128, NucleotideError> {
+    if seq.len() > MAX_BASES_U128 {
+        return Err(NucleotideError::SequenceTooLong(seq.len()));
+    }
+
+    // Pack each 32 base half with the u64 packer
+    let split = seq.len().min(32);
+    let lower = as_2bit(&seq[..split])? as u128;
+    let upper = as_2bit(&seq[split..])? as u128;
+    Ok(lower | (upper << 64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_2bit_u128_errors() {
+        assert_eq!(
+            as_2bit_u128(&[b'A'; 65]),
+            Err(NucleotideError::SequenceTooLong(65))
+        );
+        let mut seq = [b'A'; 40];
+        seq[36] = b'N';
+        assert_eq!(as_2bit_u128(&seq), Err(NucleotideError::InvalidBase(b'N')));
+    }
+
+    #[test]
+    fn test_as_2bit_u128_halves() {
+        let seq = b"ACGTACGTACGTACGTACGTACGTACGTACGTTTGCA";
+        let packed = as_2bit_u128(seq).unwrap();
+        assert_eq!(packed as u64, as_2bit(&seq[..32]).unwrap());
+        assert_eq!((packed >> 64) as u64, as_2bit(&seq[32..]).unwrap());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::NucleotideError;
+use crate::{NucleotideError, MAX_BASES};
 use std::arch::aarch64::*;
 
 #[inline(always)]
@@ -34,7 +34,7 @@ pub unsafe fn from_2bit_simd(
     expected_size: usize,
     sequence: &mut Vec<u8>,
 ) -> Result<(), NucleotideError> {
-    if expected_size > 32 {
+    if expected_size > MAX_BASES {
         return Err(NucleotideError::InvalidLength(expected_size));
     }
 
@@ -0,0 +1,78 @@
+use crate::utils::packing::validate_alphabet;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Converts a 2-bit packed representation using a custom code order back into a nucleotide sequence.
+///
+/// This reverses the packing performed by [`as_2bit_with_alphabet`](crate::as_2bit_with_alphabet)
+/// with the same `alphabet`.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `expected_size` - The number of bases to unpack
+/// * `sequence` - The buffer to write the unpacked nucleotides to
+/// * `alphabet` - The uppercase base assigned to codes 0 through 3
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidAlphabet` if `alphabet` is not a permutation of A, C, G, T.
+///
+/// Returns `NucleotideError::InvalidLength` if `expected_size` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit_with_alphabet, from_2bit_with_alphabet};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit_with_alphabet(b"ACGT", *b"ACTG")?;
+/// let mut unpacked = Vec::new();
+/// from_2bit_with_alphabet(packed, 4, &mut unpacked, *b"ACTG")?;
+/// assert_eq!(&unpacked, b"ACGT");
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_2bit_with_alphabet(
+    packed: u64,
+    expected_size: usize,
+    sequence: &mut Vec<u8>,
+    alphabet: [u8; 4],
+) -> Result<(), NucleotideError> {
+    validate_alphabet(alphabet)?;
+    if expected_size > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(expected_size));
+    }
+
+    sequence.extend((0..expected_size).map(|i| alphabet[((packed >> (i * 2)) & 0b11) as usize]));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit_with_alphabet;
+
+    #[test]
+    fn test_permuted_alphabet_round_trip() {
+        let seq = b"ACTGGAAAATTTTAAGGACTGGAAAATTTTAA";
+        for alphabet in [*b"ACGT", *b"ACTG", *b"TGCA", *b"GATC"] {
+            let packed = as_2bit_with_alphabet(seq, alphabet).unwrap();
+            let mut unpacked = Vec::new();
+            from_2bit_with_alphabet(packed, seq.len(), &mut unpacked, alphabet).unwrap();
+            assert_eq!(&unpacked, seq);
+        }
+    }
+
+    #[test]
+    fn test_from_2bit_with_alphabet_errors() {
+        let mut unpacked = Vec::new();
+        assert_eq!(
+            from_2bit_with_alphabet(0, 4, &mut unpacked, *b"AAAA"),
+            Err(NucleotideError::InvalidAlphabet(*b"AAAA"))
+        );
+        assert_eq!(
+            from_2bit_with_alphabet(0, 33, &mut unpacked, *b"ACGT"),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::utils::functions::BASES;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Converts a bit-sliced sequence back into a nucleotide sequence.
+///
+/// This reverses the packing performed by
+/// [`as_2bit_bitsliced`](crate::as_2bit_bitsliced).
+///
+/// # Arguments
+///
+/// * `low_bits` - The low bit of each base, base `i` at bit `i`
+/// * `high_bits` - The high bit of each base, base `i` at bit `i`
+/// * `expected_size` - The number of bases to unpack
+/// * `sequence` - The buffer the ASCII sequence is appended to
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `expected_size` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit_bitsliced, from_2bit_bitsliced};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (low, high, len) = as_2bit_bitsliced(b"GATTACA")?;
+///
+/// let mut unpacked = Vec::new();
+/// from_2bit_bitsliced(low, high, len, &mut unpacked)?;
+/// assert_eq!(&unpacked, b"GATTACA");
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_2bit_bitsliced(
+    low_bits: u32,
+    high_bits: u32,
+    expected_size: usize,
+    sequence: &mut Vec<u8>,
+) -> Result<(), NucleotideError> {
+    if expected_size > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(expected_size));
+    }
+
+    sequence.extend((0..expected_size).map(|i| {
+        let code = ((low_bits >> i) & 1) | (((high_bits >> i) & 1) << 1);
+        BASES[code as usize]
+    }));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit_bitsliced;
+
+    #[test]
+    fn test_bitsliced_round_trip() {
+        for len in [0, 1, 7, 31, 32] {
+            let seq: Vec<u8> = (0..len).map(|i| b"ACGTTGCA"[(i * 5 + i / 3) % 8]).collect();
+            let (low, high, n) = as_2bit_bitsliced(&seq).unwrap();
+
+            let mut unpacked = Vec::new();
+            from_2bit_bitsliced(low, high, n, &mut unpacked).unwrap();
+            assert_eq!(unpacked, seq, "Failed for length {}", len);
+        }
+    }
+
+    #[test]
+    fn test_from_2bit_bitsliced_invalid_length() {
+        assert_eq!(
+            from_2bit_bitsliced(0, 0, 33, &mut Vec::new()),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::utils::functions::BASES;
+use crate::NucleotideError;
+
+/// Unpacks bytes holding four bases each back into a nucleotide sequence.
+///
+/// This reverses the packing performed by
+/// [`as_2bit_bytes`](crate::as_2bit_bytes), so the first base of each byte
+/// is read from its two most-significant bits. Bits beyond `len` bases are ignored.
+///
+/// # Arguments
+///
+/// * `bytes` - The packed bytes, four bases per byte
+/// * `len` - The number of bases to unpack
+///
+/// # Returns
+///
+/// Returns a `Vec<u8>` containing the ASCII sequence.
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `bytes` holds fewer than `len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit_bytes, from_2bit_bytes};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let bytes = as_2bit_bytes(b"GATTACA")?;
+/// assert_eq!(from_2bit_bytes(&bytes, 7)?, b"GATTACA");
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_2bit_bytes(bytes: &[u8], len: usize) -> Result<Vec<u8>, NucleotideError> {
+    if len.div_ceil(4) > bytes.len() {
+        return Err(NucleotideError::InvalidLength(len));
+    }
+
+    Ok((0..len)
+        .map(|i| {
+            let code = (bytes[i / 4] >> (6 - 2 * (i % 4))) & 0b11;
+            BASES[code as usize]
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit_bytes;
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let seq = b"ACGTTGCAAG";
+        let bytes = as_2bit_bytes(seq).unwrap();
+        assert_eq!(bytes.len(), 3);
+        assert_eq!(from_2bit_bytes(&bytes, seq.len()).unwrap(), seq);
+        assert_eq!(from_2bit_bytes(&bytes, 4).unwrap(), b"ACGT");
+    }
+
+    #[test]
+    fn test_from_2bit_bytes_too_short() {
+        assert_eq!(
+            from_2bit_bytes(&[0xFF, 0xFF], 9),
+            Err(NucleotideError::InvalidLength(9))
+        );
+        assert!(from_2bit_bytes(&[], 0).unwrap().is_empty());
+    }
+}
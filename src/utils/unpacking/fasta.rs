@@ -0,0 +1,92 @@
+use super::from_2bit_multi;
+use crate::NucleotideError;
+
+/// Decodes a block encoded sequence into a line-wrapped FASTA sequence string.
+///
+/// A newline is inserted between every `line_width` bases. No newline is added
+/// after the final line, and an empty sequence gives an empty string.
+///
+/// # Arguments
+///
+/// * `blocks` - The 2-bit packed blocks, 32 bases per block
+/// * `total_len` - The number of bases in the sequence
+/// * `line_width` - The number of bases per line, or 0 to keep a single line
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `blocks` is too short to hold
+/// `total_len` bases.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, blocks_to_fasta_string};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let blocks = [as_2bit(b"ACGTACGTAC")?];
+/// assert_eq!(blocks_to_fasta_string(&blocks, 10, 4)?, "ACGT\nACGT\nAC");
+/// assert_eq!(blocks_to_fasta_string(&blocks, 10, 0)?, "ACGTACGTAC");
+/// # Ok(())
+/// # }
+/// ```
+pub fn blocks_to_fasta_string(
+    blocks: &[u64],
+    total_len: usize,
+    line_width: usize,
+) -> Result<String, NucleotideError> {
+    if blocks.len() < total_len.div_ceil(32) {
+        return Err(NucleotideError::InvalidLength(total_len));
+    }
+    if total_len == 0 {
+        return Ok(String::new());
+    }
+
+    let mut seq = Vec::with_capacity(total_len);
+    from_2bit_multi(blocks, total_len, &mut seq)?;
+
+    let width = if line_width == 0 {
+        total_len
+    } else {
+        line_width
+    };
+    let mut out = String::with_capacity(total_len + total_len / width);
+    for (i, line) in seq.chunks(width).enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.extend(line.iter().map(|&base| base as char));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode_alloc;
+
+    #[test]
+    fn test_blocks_to_fasta_string() {
+        let seq: Vec<u8> = b"ACGTTGCAAG".iter().cycle().take(100).copied().collect();
+        let blocks = encode_alloc(&seq).unwrap();
+
+        let fasta = blocks_to_fasta_string(&blocks, 100, 60).unwrap();
+        let lines: Vec<&str> = fasta.split('\n').collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].as_bytes(), &seq[..60]);
+        assert_eq!(lines[1].as_bytes(), &seq[60..]);
+
+        let single = blocks_to_fasta_string(&blocks, 100, 0).unwrap();
+        assert_eq!(single.as_bytes(), &seq[..]);
+        let exact = blocks_to_fasta_string(&blocks, 100, 50).unwrap();
+        assert_eq!(exact.split('\n').count(), 2);
+    }
+
+    #[test]
+    fn test_blocks_to_fasta_string_edge_cases() {
+        assert_eq!(blocks_to_fasta_string(&[], 0, 60), Ok(String::new()));
+        assert_eq!(
+            blocks_to_fasta_string(&[0], 33, 60),
+            Err(NucleotideError::InvalidLength(33))
+        );
+    }
+}
@@ -1,10 +1,23 @@
 #[cfg(all(target_arch = "aarch64", not(feature = "nosimd")))]
 mod aarch64;
+mod alphabet;
 #[cfg(all(target_arch = "x86_64", not(feature = "nosimd")))]
 mod avx;
+mod bitsliced;
+mod bytes;
+mod fasta;
 mod naive;
+mod records;
+mod wide;
 
-use crate::NucleotideError;
+pub use alphabet::from_2bit_with_alphabet;
+pub use bitsliced::from_2bit_bitsliced;
+pub use bytes::from_2bit_bytes;
+pub use fasta::blocks_to_fasta_string;
+pub use records::decode_records;
+pub use wide::from_2bit_u128;
+
+use crate::{NucleotideError, MAX_BASES};
 
 /// Converts an arbitrary sized 2-bit packed representation back into a nucleotide sequence.
 pub fn from_2bit_multi(
@@ -181,6 +194,59 @@ pub fn from_2bit_alloc(packed: u64, expected_size: usize) -> Result<Vec<u8>, Nuc
     Ok(sequence)
 }
 
+/// Unpacks a 2-bit packed sequence and pads it to a fixed width.
+///
+/// The first `actual_len` bases are unpacked and then `pad` is repeated until the
+/// output is `pad_to` bytes long.
+/// Only the unpacked portion is limited to 32 bases, `pad_to` may be larger.
+///
+/// # Arguments
+///
+/// * `packed` - A u64 containing the 2-bit packed sequence
+/// * `actual_len` - The number of bases to unpack (<= 32)
+/// * `pad_to` - The length of the output (>= `actual_len`)
+/// * `pad` - The byte to pad with, which must be printable ASCII
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `actual_len` is greater than 32
+/// or greater than `pad_to`.
+///
+/// Returns `NucleotideError::InvalidBase` if `pad` is not a printable ASCII character.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit, from_2bit_padded};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let packed = as_2bit(b"ACG")?;
+/// assert_eq!(from_2bit_padded(packed, 3, 5, b'N')?, b"ACGNN");
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_2bit_padded(
+    packed: u64,
+    actual_len: usize,
+    pad_to: usize,
+    pad: u8,
+) -> Result<Vec<u8>, NucleotideError> {
+    if actual_len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(actual_len));
+    }
+    if actual_len > pad_to {
+        return Err(NucleotideError::InvalidLength(pad_to));
+    }
+    if !pad.is_ascii_graphic() {
+        return Err(NucleotideError::InvalidBase(pad));
+    }
+
+    let mut sequence = Vec::with_capacity(pad_to);
+    from_2bit(packed, actual_len, &mut sequence)?;
+    sequence.resize(pad_to, pad);
+    Ok(sequence)
+}
+
 #[cfg(test)]
 mod testing {
     use super::*;
@@ -213,4 +279,33 @@ mod testing {
         assert_eq!(obs_str, exp_str);
         assert_eq!(&observed, expected);
     }
+
+    #[test]
+    fn test_from_2bit_padded() {
+        let packed = 0b100100; // "ACG" in 2-bit encoding
+        assert_eq!(from_2bit_padded(packed, 3, 5, b'N').unwrap(), b"ACGNN");
+        assert_eq!(from_2bit_padded(packed, 3, 3, b'N').unwrap(), b"ACG");
+        assert_eq!(from_2bit_padded(packed, 0, 2, b'-').unwrap(), b"--");
+        assert_eq!(from_2bit_padded(packed, 3, 40, b'N').unwrap().len(), 40);
+    }
+
+    #[test]
+    fn test_from_2bit_padded_errors() {
+        assert_eq!(
+            from_2bit_padded(0, 33, 40, b'N'),
+            Err(NucleotideError::InvalidLength(33))
+        );
+        assert_eq!(
+            from_2bit_padded(0, 4, 3, b'N'),
+            Err(NucleotideError::InvalidLength(3))
+        );
+        assert_eq!(
+            from_2bit_padded(0, 3, 5, b'\n'),
+            Err(NucleotideError::InvalidBase(b'\n'))
+        );
+        assert_eq!(
+            from_2bit_padded(0, 3, 5, 0xFF),
+            Err(NucleotideError::InvalidBase(0xFF))
+        );
+    }
 }
@@ -1,11 +1,11 @@
-use crate::NucleotideError;
+use crate::{NucleotideError, MAX_BASES};
 
 pub fn from_2bit(
     packed: u64,
     expected_size: usize,
     sequence: &mut Vec<u8>,
 ) -> Result<(), NucleotideError> {
-    if expected_size > 32 {
+    if expected_size > MAX_BASES {
         return Err(NucleotideError::InvalidLength(expected_size));
     }
 
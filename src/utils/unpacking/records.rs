@@ -0,0 +1,77 @@
+use crate::utils::functions::BASES;
+use crate::{NucleotideError, MAX_BASES};
+
+/// Lazily decodes a buffer of fixed-length packed records.
+///
+/// Each u64 of `blocks` is treated as a separate record of `record_len` bases,
+/// as produced by [`as_2bit_batch_uniform`](crate::as_2bit_batch_uniform), and
+/// is unpacked into its own ASCII sequence as the iterator advances.
+///
+/// # Arguments
+///
+/// * `blocks` - The packed records, one per u64
+/// * `record_len` - The number of bases in each record
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `record_len` is greater than 32.
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit_batch_uniform, decode_records};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut packed = [0u64; 2];
+/// as_2bit_batch_uniform(b"ACGTTTGA", 4, &mut packed)?;
+///
+/// let records: Vec<Vec<u8>> = decode_records(&packed, 4)?.collect();
+/// assert_eq!(records, vec![b"ACGT".to_vec(), b"TTGA".to_vec()]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_records(
+    blocks: &[u64],
+    record_len: usize,
+) -> Result<impl Iterator<Item = Vec<u8>> + '_, NucleotideError> {
+    if record_len > MAX_BASES {
+        return Err(NucleotideError::InvalidLength(record_len));
+    }
+
+    Ok(blocks.iter().map(move |&block| {
+        (0..record_len)
+            .map(|i| BASES[((block >> (i * 2)) & 0b11) as usize])
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit;
+
+    #[test]
+    fn test_decode_records() {
+        let seqs: [&[u8]; 3] = [b"ACGT", b"TTTT", b"GCAA"];
+        let blocks: Vec<u64> = seqs.iter().map(|s| as_2bit(s).unwrap()).collect();
+
+        let records: Vec<Vec<u8>> = decode_records(&blocks, 4).unwrap().collect();
+        assert_eq!(records, seqs.map(|s| s.to_vec()));
+    }
+
+    #[test]
+    fn test_decode_records_ignores_trailing_bits() {
+        let blocks = [as_2bit(b"CAT").unwrap() | (u64::MAX << 6)];
+        let mut records = decode_records(&blocks, 3).unwrap();
+        assert_eq!(records.next(), Some(b"CAT".to_vec()));
+        assert_eq!(records.next(), None);
+    }
+
+    #[test]
+    fn test_decode_records_invalid_length() {
+        assert!(matches!(
+            decode_records(&[0], 33),
+            Err(NucleotideError::InvalidLength(33))
+        ));
+    }
+}
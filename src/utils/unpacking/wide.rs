@@ -0,0 +1,74 @@
+use super::from_2bit;
+use crate::{NucleotideError, MAX_BASES_U128};
+
+/// Converts a 2-bit packed `u128` back into a nucleotide sequence.
+///
+/// This reverses the packing performed by [`as_2bit_u128`](crate::as_2bit_u128)
+/// and mirrors [`from_2bit`] with the limit raised to 64 bases.
+///
+/// # Arguments
+///
+/// * `packed` - A u128 containing the 2-bit packed sequence
+/// * `expected_size` - The number of bases to unpack
+/// * `sequence` - The buffer the ASCII sequence is appended to
+///
+/// # Errors
+///
+/// Returns `NucleotideError::InvalidLength` if `expected_size` is greater than 64
+/// (as a u128 can only store 64 * 2 bits).
+///
+/// # Examples
+///
+/// ```rust
+/// use bitnuc::{as_2bit_u128, from_2bit_u128};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let seq = b"ACGTACGTACGTACGTACGTACGTACGTACGTACGTACGT";
+/// let packed = as_2bit_u128(seq)?;
+///
+/// let mut unpacked = Vec::new();
+/// from_2bit_u128(packed, seq.len(), &mut unpacked)?;
+/// assert_eq!(&unpacked, seq);
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_2bit_u128(
+    packed: u128,
+    expected_size: usize,
+    sequence: &mut Vec<u8>,
+) -> Result<(), NucleotideError> {
+    if expected_size > MAX_BASES_U128 {
+        return Err(NucleotideError::InvalidLength(expected_size));
+    }
+
+    // Unpack each 32 base half with the u64 unpacker
+    let split = expected_size.min(32);
+    from_2bit(packed as u64, split, sequence)?;
+    from_2bit((packed >> 64) as u64, expected_size - split, sequence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::as_2bit_u128;
+
+    #[test]
+    fn test_u128_round_trip() {
+        for len in [0, 1, 32, 33, 48, 64] {
+            let seq: Vec<u8> = (0..len).map(|i| b"ACGTTGCA"[(i * 5 + i / 3) % 8]).collect();
+            let packed = as_2bit_u128(&seq).unwrap();
+
+            let mut unpacked = Vec::new();
+            from_2bit_u128(packed, len, &mut unpacked).unwrap();
+            assert_eq!(unpacked, seq, "Failed for length {}", len);
+        }
+    }
+
+    #[test]
+    fn test_from_2bit_u128_invalid_length() {
+        assert_eq!(
+            from_2bit_u128(0, 65, &mut Vec::new()),
+            Err(NucleotideError::InvalidLength(65))
+        );
+    }
+}